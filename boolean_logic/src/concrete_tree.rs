@@ -1,5 +1,8 @@
 use crate::{
-    error::{Error, Result},
+    diagnostic::check_rpn,
+    error::Result,
+    infix::parse_infix,
+    lexer::{Lexeme, Token},
     operation::Operation,
 };
 
@@ -20,41 +23,35 @@ pub struct ConcreteFormulaTree {
 
 impl ConcreteFormulaTree {
     pub fn build(formula: &str) -> Result<Self> {
+        Self::build_from_lexemes(check_rpn(formula, false, is_constant)?)
+    }
+
+    pub fn build_from_infix(formula: &str) -> Result<Self> {
+        Self::build_from_lexemes(parse_infix(formula, is_constant)?)
+    }
+
+    fn build_from_lexemes(lexemes: Vec<Lexeme>) -> Result<Self> {
         let mut stack = vec![];
-        for character in formula.chars() {
-            match character {
-                '0' => stack.push(FormulaNode {
-                    node_type: NodeType::Leaf(false),
+        for lexeme in lexemes {
+            match lexeme.token {
+                Token::Operand(constant) => stack.push(FormulaNode {
+                    node_type: NodeType::Leaf(constant == "1"),
                     left_child: None,
                     right_child: None,
                 }),
-                '1' => stack.push(FormulaNode {
-                    node_type: NodeType::Leaf(true),
-                    left_child: None,
-                    right_child: None,
-                }),
-                '!' => Self::build_negation_node(&mut stack)?,
-                '&' => Self::build_operation_node(Operation::And, &mut stack)?,
-                '|' => Self::build_operation_node(Operation::Or, &mut stack)?,
-                '^' => Self::build_operation_node(Operation::Xor, &mut stack)?,
-                '>' => Self::build_operation_node(Operation::IfThen, &mut stack)?,
-                '=' => Self::build_operation_node(Operation::Equality, &mut stack)?,
-                _ => return Err(Error::InvalidFormulaSyntax),
+                Token::Operation(symbol) => match Operation::new(symbol) {
+                    (Operation::Not, _) => Self::build_negation_node(&mut stack),
+                    (operation, _) => Self::build_operation_node(operation, &mut stack),
+                },
+                _ => unreachable!("The parsers should have rejected other tokens"),
             }
         }
-        if stack.len() != 1 {
-            Err(Error::InvalidFormulaGrammar)
-        } else {
-            Ok(Self {
-                root: stack.pop().expect("This should not be None at this point"),
-            })
-        }
+        Ok(Self {
+            root: stack.pop().expect("This should not be None at this point"),
+        })
     }
 
-    fn build_negation_node(stack: &mut Vec<FormulaNode>) -> Result<()> {
-        if stack.len() < 1 {
-            return Err(Error::InvalidFormulaGrammar);
-        }
+    fn build_negation_node(stack: &mut Vec<FormulaNode>) {
         let child = stack
             .pop()
             .expect("Stack should be assured to have 1 element at this point");
@@ -63,13 +60,9 @@ impl ConcreteFormulaTree {
             left_child: Some(Box::new(child)),
             right_child: None,
         });
-        Ok(())
     }
 
-    fn build_operation_node(operation: Operation, stack: &mut Vec<FormulaNode>) -> Result<()> {
-        if stack.len() < 2 {
-            return Err(Error::InvalidFormulaGrammar);
-        }
+    fn build_operation_node(operation: Operation, stack: &mut Vec<FormulaNode>) {
         let right_child = stack
             .pop()
             .expect("Stack should be assured to have 2 elements at this point");
@@ -81,7 +74,6 @@ impl ConcreteFormulaTree {
             right_child: Some(Box::new(right_child)),
             left_child: Some(Box::new(left_child)),
         });
-        Ok(())
     }
 
     pub fn resolve_tree(self) -> bool {
@@ -93,7 +85,7 @@ impl ConcreteFormulaTree {
         let left_child_value;
         if let Some(right_child) = &node.right_child {
             right_child_value = match right_child.node_type {
-                NodeType::Node(_) => Self::resolve_node(&right_child),
+                NodeType::Node(_) => Self::resolve_node(right_child),
                 NodeType::Leaf(value) => value,
             }
         } else {
@@ -101,7 +93,7 @@ impl ConcreteFormulaTree {
         }
         if let Some(left_child) = &node.left_child {
             left_child_value = match left_child.node_type {
-                NodeType::Node(_) => Self::resolve_node(&left_child),
+                NodeType::Node(_) => Self::resolve_node(left_child),
                 NodeType::Leaf(value) => value,
             }
         } else {
@@ -117,9 +109,14 @@ impl ConcreteFormulaTree {
     }
 }
 
+fn is_constant(name: &str) -> bool {
+    name == "0" || name == "1"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
     #[test]
     fn build_tree_ok() {
         let tree = ConcreteFormulaTree::build("00&");
@@ -131,14 +128,33 @@ mod tests {
     #[test]
     fn eval_formula_err_invalid_characters() {
         let tree = ConcreteFormulaTree::build("00u&");
-        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax)));
+        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax(_))));
     }
 
     #[test]
     fn eval_formula_err_invalid_grammar() {
         let tree = ConcreteFormulaTree::build("000&");
-        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar)));
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
         let tree = ConcreteFormulaTree::build("00&11&");
-        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar)));
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
+    }
+
+    #[test]
+    fn build_from_infix_matches_rpn() {
+        let formulas = [
+            ("1 & 0", "10&"),
+            ("(1 & 1) | !1 -> 0", "11&1!|0>"),
+            ("1 = 0 | 1 | 1", "101|1|="),
+            ("!(0 ^ 1) -> 1 -> 0", "01^!10>>"),
+        ];
+        for (infix, rpn) in formulas {
+            let infix_tree = ConcreteFormulaTree::build_from_infix(infix).unwrap();
+            let rpn_tree = ConcreteFormulaTree::build(rpn).unwrap();
+            assert_eq!(infix_tree.resolve_tree(), rpn_tree.resolve_tree());
+        }
+        let tree = ConcreteFormulaTree::build_from_infix("1 & u");
+        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax(_))));
+        let tree = ConcreteFormulaTree::build_from_infix("(1 & 0");
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
     }
 }
@@ -0,0 +1,251 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{Error, Result},
+    lexer::{tokenize, Lexeme, Token},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueKind {
+    UnknownSymbol,
+    MissingOperands { expected: u8, found: usize },
+    LeftoverOperands,
+    ExpectedOperand,
+    MissingOperation,
+    UnclosedParenthesis,
+    UnmatchedParenthesis,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormulaIssue {
+    pub index: usize,
+    pub symbol: Option<String>,
+    pub depth: usize,
+    pub kind: IssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostics {
+    pub formula: String,
+    pub issues: Vec<FormulaIssue>,
+}
+
+impl Diagnostics {
+    pub fn new(formula: &str) -> Self {
+        Self {
+            formula: formula.to_string(),
+            issues: vec![],
+        }
+    }
+
+    pub fn push(&mut self, index: usize, symbol: Option<String>, depth: usize, kind: IssueKind) {
+        self.issues.push(FormulaIssue {
+            index,
+            symbol,
+            depth,
+            kind,
+        });
+    }
+
+    // Issues are found in parsing order, they are reported left to right
+    pub fn into_result(mut self) -> Result<()> {
+        self.issues.sort_by_key(|issue| issue.index);
+        if self.issues.is_empty() {
+            Ok(())
+        } else if self
+            .issues
+            .iter()
+            .any(|issue| issue.kind == IssueKind::UnknownSymbol)
+        {
+            Err(Error::InvalidFormulaSyntax(self))
+        } else {
+            Err(Error::InvalidFormulaGrammar(self))
+        }
+    }
+}
+
+impl Display for FormulaIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match &self.symbol {
+            Some(symbol) => format!("'{symbol}'"),
+            None => "end of formula".to_string(),
+        };
+        match &self.kind {
+            IssueKind::UnknownSymbol => write!(f, "unknown symbol {symbol}")?,
+            IssueKind::MissingOperands { expected, found } => {
+                write!(f, "{symbol} expects {expected} operand(s), found {found}")?
+            }
+            IssueKind::LeftoverOperands => write!(
+                f,
+                "formula resolves to {} operand(s) instead of 1",
+                self.depth
+            )?,
+            IssueKind::ExpectedOperand => write!(f, "expected an operand, found {symbol}")?,
+            IssueKind::MissingOperation => write!(f, "expected an operation before {symbol}")?,
+            IssueKind::UnclosedParenthesis => write!(f, "parenthesis is never closed")?,
+            IssueKind::UnmatchedParenthesis => write!(f, "parenthesis was never opened")?,
+        }
+        write!(f, " (index {}, depth {})", self.index, self.depth)
+    }
+}
+
+// Renders the formula once per issue with carets under the faulty symbol
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for issue in self.issues.iter() {
+            writeln!(f)?;
+            writeln!(f, "  {}", self.formula)?;
+            let width = issue
+                .symbol
+                .as_ref()
+                .map_or(1, |symbol| symbol.chars().count());
+            write!(
+                f,
+                "  {}{} {issue}",
+                " ".repeat(issue.index),
+                "^".repeat(width)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Walks a reverse polish formula with a depth counter instead of a node stack,
+// so every problem is collected before the tree is built
+pub fn check_rpn(
+    formula: &str,
+    separated: bool,
+    is_operand: fn(&str) -> bool,
+) -> Result<Vec<Lexeme>> {
+    let mut diagnostics = Diagnostics::new(formula);
+    let lexemes = tokenize(formula, separated, is_operand);
+    let mut depth = 0;
+    for lexeme in lexemes.iter() {
+        let expected = match lexeme.token {
+            Token::Operation('!') => 1,
+            Token::Operation(_) => 2,
+            Token::Operand(_) => {
+                depth += 1;
+                continue;
+            }
+            Token::LeftParenthesis | Token::RightParenthesis | Token::Unknown(_) => {
+                diagnostics.push(
+                    lexeme.index,
+                    Some(lexeme.symbol()),
+                    depth,
+                    IssueKind::UnknownSymbol,
+                );
+                continue;
+            }
+        };
+        if depth < expected as usize {
+            diagnostics.push(
+                lexeme.index,
+                Some(lexeme.symbol()),
+                depth,
+                IssueKind::MissingOperands {
+                    expected,
+                    found: depth,
+                },
+            );
+            depth = expected as usize;
+        }
+        depth -= expected as usize - 1;
+    }
+    if depth != 1 {
+        diagnostics.push(
+            formula.chars().count(),
+            None,
+            depth,
+            IssueKind::LeftoverOperands,
+        );
+    }
+    diagnostics.into_result()?;
+    Ok(lexemes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_variable(name: &str) -> bool {
+        name.chars()
+            .all(|character| character.is_ascii_alphabetic())
+    }
+
+    fn issues(result: Result<Vec<Lexeme>>) -> Vec<FormulaIssue> {
+        match result {
+            Err(Error::InvalidFormulaSyntax(diagnostics))
+            | Err(Error::InvalidFormulaGrammar(diagnostics)) => diagnostics.issues,
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn check_rpn_ok() {
+        assert!(check_rpn("AB&C!|", false, is_variable).is_ok());
+        assert!(check_rpn("A", false, is_variable).is_ok());
+    }
+
+    #[test]
+    fn check_rpn_reports_every_issue() {
+        let result = check_rpn("A&?|", false, is_variable);
+        assert!(matches!(result, Err(Error::InvalidFormulaSyntax(_))));
+        assert_eq!(
+            issues(result),
+            vec![
+                FormulaIssue {
+                    index: 1,
+                    symbol: Some("&".to_string()),
+                    depth: 1,
+                    kind: IssueKind::MissingOperands {
+                        expected: 2,
+                        found: 1
+                    },
+                },
+                FormulaIssue {
+                    index: 2,
+                    symbol: Some("?".to_string()),
+                    depth: 1,
+                    kind: IssueKind::UnknownSymbol,
+                },
+                FormulaIssue {
+                    index: 3,
+                    symbol: Some("|".to_string()),
+                    depth: 1,
+                    kind: IssueKind::MissingOperands {
+                        expected: 2,
+                        found: 1
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_rpn_leftover_operands() {
+        let result = check_rpn("AB&CD&", false, is_variable);
+        assert!(matches!(result, Err(Error::InvalidFormulaGrammar(_))));
+        assert_eq!(
+            issues(result),
+            vec![FormulaIssue {
+                index: 6,
+                symbol: None,
+                depth: 2,
+                kind: IssueKind::LeftoverOperands,
+            }]
+        );
+        let result = check_rpn("", false, is_variable);
+        assert_eq!(issues(result)[0].depth, 0);
+    }
+
+    #[test]
+    fn diagnostics_render_caret() {
+        let result = check_rpn("AB&?", false, is_variable);
+        let message = result.unwrap_err().to_string();
+        assert_eq!(
+            message,
+            "Formula contains unknown characters\n  AB&?\n     ^ unknown symbol '?' (index 3, depth 1)"
+        );
+    }
+}
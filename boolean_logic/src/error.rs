@@ -0,0 +1,35 @@
+use crate::diagnostic::Diagnostics;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidFormulaSyntax(Diagnostics),
+    InvalidFormulaGrammar(Diagnostics),
+    InvalidFormulaResult,
+    MissingChildNode,
+    TooManyVariables(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidFormulaSyntax(diagnostics) => {
+                write!(f, "Formula contains unknown characters{diagnostics}")
+            }
+            Error::InvalidFormulaGrammar(diagnostics) => {
+                write!(f, "Formula grammar cannot be resolved{diagnostics}")
+            }
+            Error::InvalidFormulaResult => write!(f, "Formula result is invalid"),
+            Error::MissingChildNode => write!(f, "Operation node is missing a child"),
+            Error::TooManyVariables(count) => {
+                write!(
+                    f,
+                    "{count} variables are too many to enumerate a truth table"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,122 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    operation::Operation,
+    tree_node::{NodeType, TreeNode},
+};
+
+#[derive(Debug)]
+enum FlatNodeType {
+    Variable(usize),
+    Operation(Operation),
+}
+
+#[derive(Debug)]
+struct FlatNode {
+    node_type: FlatNodeType,
+    left_child: Option<usize>,
+    right_child: Option<usize>,
+    parent: Option<usize>,
+    value: bool,
+}
+
+// The formula tree flattened in post-order, every node caching its value and
+// every variable knowing its leaves so a flip only re-evaluates their ancestors
+#[derive(Debug)]
+pub struct IncrementalEvaluator {
+    nodes: Vec<FlatNode>,
+    leaves: Vec<Vec<usize>>,
+    values: Vec<bool>,
+    last_reevaluated: usize,
+}
+
+impl IncrementalEvaluator {
+    pub fn build(root: &TreeNode, number_of_variables: usize) -> Self {
+        let mut evaluator = Self {
+            nodes: vec![],
+            leaves: vec![vec![]; number_of_variables],
+            values: vec![false; number_of_variables],
+            last_reevaluated: 0,
+        };
+        evaluator.push_node(root);
+        evaluator.last_reevaluated = evaluator.nodes.len();
+        evaluator
+    }
+
+    fn push_node(&mut self, node: &TreeNode) -> usize {
+        let left_child = node.left_child().map(|child| self.push_node(child));
+        let right_child = node.right_child().map(|child| self.push_node(child));
+        let node_type = match &node.node_type {
+            NodeType::Leaf(variable) => FlatNodeType::Variable(*variable),
+            NodeType::Node(operation) => FlatNodeType::Operation(operation.clone()),
+        };
+        let index = self.nodes.len();
+        if let FlatNodeType::Variable(variable) = node_type {
+            self.leaves[variable].push(index);
+        }
+        for child in [left_child, right_child].into_iter().flatten() {
+            self.nodes[child].parent = Some(index);
+        }
+        self.nodes.push(FlatNode {
+            node_type,
+            left_child,
+            right_child,
+            parent: None,
+            value: false,
+        });
+        self.nodes[index].value = self.evaluate_node(index);
+        index
+    }
+
+    fn evaluate_node(&self, index: usize) -> bool {
+        let node = &self.nodes[index];
+        match &node.node_type {
+            FlatNodeType::Variable(variable) => self.values[*variable],
+            FlatNodeType::Operation(operation) => {
+                let left_value = node
+                    .left_child
+                    .map(|child| self.nodes[child].value)
+                    .expect("An operation node should have a left_child");
+                let right_value = node
+                    .right_child
+                    .is_some_and(|child| self.nodes[child].value);
+                operation.get_operation_closure()(left_value, right_value)
+            }
+        }
+    }
+
+    pub fn flip(&mut self, variable: usize) -> bool {
+        self.values[variable] = !self.values[variable];
+        let mut dirty = BTreeSet::new();
+        for &leaf in self.leaves[variable].iter() {
+            let mut current = Some(leaf);
+            while let Some(index) = current {
+                if !dirty.insert(index) {
+                    break;
+                }
+                current = self.nodes[index].parent;
+            }
+        }
+        // Post-order indices guarantee children are refreshed before parents
+        for &index in dirty.iter() {
+            self.nodes[index].value = self.evaluate_node(index);
+        }
+        self.last_reevaluated = dirty.len();
+        self.value()
+    }
+
+    pub fn value(&self) -> bool {
+        self.nodes
+            .last()
+            .expect("The flattened tree should never be empty")
+            .value
+    }
+
+    pub fn values(&self) -> &[bool] {
+        &self.values
+    }
+
+    pub fn last_reevaluated(&self) -> usize {
+        self.last_reevaluated
+    }
+}
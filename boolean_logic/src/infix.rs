@@ -0,0 +1,286 @@
+use crate::{
+    diagnostic::{Diagnostics, IssueKind},
+    error::Result,
+    lexer::{to_rpn_string, tokenize, Lexeme, Token},
+    operation::Operation,
+};
+
+struct InfixParser {
+    lexemes: Vec<Lexeme>,
+    position: usize,
+    depth: usize,
+    end_index: usize,
+    output: Vec<Lexeme>,
+    diagnostics: Diagnostics,
+}
+
+// Reorders an infix formula such as "(A & B) | !C -> D" into the reverse
+// polish lexemes the tree builders already understand
+pub fn parse_infix(input: &str, is_operand: fn(&str) -> bool) -> Result<Vec<Lexeme>> {
+    let mut diagnostics = Diagnostics::new(input);
+    let lexemes = tokenize(input, true, is_operand)
+        .into_iter()
+        .filter(|lexeme| match &lexeme.token {
+            Token::Unknown(symbol) => {
+                diagnostics.push(
+                    lexeme.index,
+                    Some(symbol.clone()),
+                    0,
+                    IssueKind::UnknownSymbol,
+                );
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    let mut parser = InfixParser {
+        lexemes,
+        position: 0,
+        depth: 0,
+        end_index: input.chars().count(),
+        output: vec![],
+        diagnostics,
+    };
+    parser.parse_operand();
+    parser.parse_operations(0);
+    // A stray ')' ends every expression level, skip it and keep going
+    while let Some(lexeme) = parser.next_lexeme() {
+        parser.push_issue(Some(lexeme), IssueKind::UnmatchedParenthesis);
+        parser.parse_operations(0);
+    }
+    parser.diagnostics.into_result()?;
+    Ok(parser.output)
+}
+
+pub fn infix_to_rpn(input: &str, is_operand: fn(&str) -> bool) -> Result<String> {
+    Ok(to_rpn_string(&parse_infix(input, is_operand)?))
+}
+
+fn binary_precedence(symbol: char) -> Option<(u8, bool)> {
+    match Operation::new(symbol) {
+        (Operation::Not, _) => None,
+        (operation, _) => Some(operation.precedence()),
+    }
+}
+
+impl InfixParser {
+    fn peek_lexeme(&self) -> Option<Lexeme> {
+        self.lexemes.get(self.position).cloned()
+    }
+
+    fn next_lexeme(&mut self) -> Option<Lexeme> {
+        let lexeme = self.peek_lexeme();
+        if lexeme.is_some() {
+            self.position += 1;
+        }
+        lexeme
+    }
+
+    fn push_issue(&mut self, lexeme: Option<Lexeme>, kind: IssueKind) {
+        match lexeme {
+            Some(lexeme) => {
+                self.diagnostics
+                    .push(lexeme.index, Some(lexeme.symbol()), self.depth, kind)
+            }
+            None => self
+                .diagnostics
+                .push(self.end_index, None, self.depth, kind),
+        }
+    }
+
+    fn parse_operations(&mut self, minimum_precedence: u8) {
+        while let Some(lexeme) = self.peek_lexeme() {
+            match lexeme.token {
+                Token::Operation(symbol) => {
+                    let Some((precedence, right_associative)) = binary_precedence(symbol) else {
+                        // A prefix '!' where an operation belongs
+                        self.push_issue(Some(lexeme), IssueKind::MissingOperation);
+                        self.parse_operand();
+                        continue;
+                    };
+                    if precedence < minimum_precedence {
+                        return;
+                    }
+                    self.position += 1;
+                    self.parse_operand();
+                    let next_minimum = if right_associative {
+                        precedence
+                    } else {
+                        precedence + 1
+                    };
+                    self.parse_operations(next_minimum);
+                    self.output.push(lexeme);
+                }
+                Token::Operand(_) | Token::LeftParenthesis => {
+                    self.push_issue(Some(lexeme), IssueKind::MissingOperation);
+                    self.parse_operand();
+                }
+                Token::RightParenthesis | Token::Unknown(_) => return,
+            }
+        }
+    }
+
+    // Missing operands are reported without consuming anything, so parsing
+    // goes on as if one had been there
+    fn parse_operand(&mut self) {
+        let lexeme = self.peek_lexeme();
+        match lexeme.as_ref().map(|lexeme| &lexeme.token) {
+            Some(Token::Operand(_)) => {
+                self.position += 1;
+                self.output.extend(lexeme);
+            }
+            Some(Token::Operation('!')) => {
+                self.position += 1;
+                self.parse_operand();
+                self.output.extend(lexeme);
+            }
+            Some(Token::LeftParenthesis) => {
+                self.position += 1;
+                self.depth += 1;
+                self.parse_operand();
+                self.parse_operations(0);
+                self.depth -= 1;
+                if let Some(Token::RightParenthesis) =
+                    self.peek_lexeme().map(|closing| closing.token)
+                {
+                    self.position += 1;
+                } else {
+                    self.push_issue(lexeme, IssueKind::UnclosedParenthesis);
+                }
+            }
+            _ => self.push_issue(lexeme, IssueKind::ExpectedOperand),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        diagnostic::{check_rpn, FormulaIssue},
+        error::Error,
+    };
+
+    fn to_rpn(input: &str) -> Result<String> {
+        infix_to_rpn(input, is_variable)
+    }
+
+    fn is_variable(name: &str) -> bool {
+        name.chars()
+            .all(|character| character.is_ascii_alphabetic())
+    }
+
+    fn issues(input: &str) -> Vec<(usize, IssueKind)> {
+        match to_rpn(input) {
+            Err(Error::InvalidFormulaSyntax(diagnostics))
+            | Err(Error::InvalidFormulaGrammar(diagnostics)) => diagnostics
+                .issues
+                .into_iter()
+                .map(|FormulaIssue { index, kind, .. }| (index, kind))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn infix_to_rpn_precedence() {
+        assert_eq!(to_rpn("A & B | C").unwrap(), "AB&C|");
+        assert_eq!(to_rpn("A | B & C").unwrap(), "ABC&|");
+        assert_eq!(to_rpn("A ^ B & C | D").unwrap(), "ABC&^D|");
+        assert_eq!(to_rpn("A | B -> C = D").unwrap(), "AB|C>D=");
+        assert_eq!(to_rpn("!A & !!B").unwrap(), "A!B!!&");
+        assert_eq!(to_rpn("(A & B) | !C -> D").unwrap(), "AB&C!|D>");
+    }
+
+    #[test]
+    fn build_from_infix_matches_rpn() {
+        let formulas = [
+            ("A & B", "AB&"),
+            ("(A & B) | !C -> D", "AB&C!|D>"),
+            ("A = B | C & D", "ABCD&|="),
+            ("!(A ^ B) -> C -> D", "AB^!CD>>"),
+        ];
+        for (infix, rpn) in formulas {
+            let infix_lexemes = parse_infix(infix, is_variable).unwrap();
+            let rpn_lexemes = check_rpn(rpn, false, is_variable).unwrap();
+            assert_eq!(to_rpn_string(&infix_lexemes), to_rpn_string(&rpn_lexemes));
+        }
+        assert!(matches!(
+            to_rpn("A & 1b"),
+            Err(Error::InvalidFormulaSyntax(_))
+        ));
+        assert!(matches!(
+            to_rpn("A & (B | C"),
+            Err(Error::InvalidFormulaGrammar(_))
+        ));
+    }
+
+    #[test]
+    fn infix_to_rpn_associativity() {
+        assert_eq!(to_rpn("A & B & C").unwrap(), "AB&C&");
+        assert_eq!(to_rpn("A -> B -> C").unwrap(), "ABC>>");
+        assert_eq!(to_rpn("A > B <-> C = D").unwrap(), "AB>C=D=");
+    }
+
+    #[test]
+    fn infix_to_rpn_parentheses() {
+        assert_eq!(to_rpn("A & (B | C)").unwrap(), "ABC|&");
+        assert_eq!(to_rpn("!(A ^ (B))").unwrap(), "AB^!");
+        assert_eq!(to_rpn("((A))").unwrap(), "A");
+    }
+
+    #[test]
+    fn infix_to_rpn_identifiers() {
+        assert_eq!(to_rpn("clk & !reset").unwrap(), "clk reset ! &");
+        assert_eq!(to_rpn("AB | C").unwrap(), "AB C |");
+        let lexemes = parse_infix("x | (y & z)", |_| true).unwrap();
+        let indices: Vec<usize> = lexemes.iter().map(|lexeme| lexeme.index).collect();
+        assert_eq!(indices, vec![0, 5, 9, 7, 2]);
+    }
+
+    #[test]
+    fn infix_to_rpn_err() {
+        assert!(matches!(
+            to_rpn("A - B"),
+            Err(Error::InvalidFormulaSyntax(_))
+        ));
+        assert!(matches!(
+            to_rpn("A <- B"),
+            Err(Error::InvalidFormulaSyntax(_))
+        ));
+        assert!(matches!(
+            to_rpn("A & b1"),
+            Err(Error::InvalidFormulaSyntax(_))
+        ));
+        assert_eq!(issues(""), vec![(0, IssueKind::ExpectedOperand)]);
+        assert_eq!(issues("A B"), vec![(2, IssueKind::MissingOperation)]);
+        assert_eq!(issues("A &"), vec![(3, IssueKind::ExpectedOperand)]);
+        assert_eq!(issues("(A | B"), vec![(0, IssueKind::UnclosedParenthesis)]);
+        assert_eq!(issues("A | B)"), vec![(5, IssueKind::UnmatchedParenthesis)]);
+        assert_eq!(issues("A !B"), vec![(2, IssueKind::MissingOperation)]);
+    }
+
+    #[test]
+    fn infix_to_rpn_reports_every_issue() {
+        assert_eq!(
+            issues("(A & ) | B) C ? D"),
+            vec![
+                (5, IssueKind::ExpectedOperand),
+                (10, IssueKind::UnmatchedParenthesis),
+                (12, IssueKind::MissingOperation),
+                (14, IssueKind::UnknownSymbol),
+                (16, IssueKind::MissingOperation),
+            ]
+        );
+        match to_rpn("((A & B) | C") {
+            Err(Error::InvalidFormulaGrammar(diagnostics)) => {
+                assert_eq!(diagnostics.issues[0].depth, 0);
+                assert_eq!(
+                    diagnostics.to_string(),
+                    "\n  ((A & B) | C\n  ^ parenthesis is never closed (index 0, depth 0)"
+                );
+            }
+            _ => panic!("An unclosed parenthesis should be a grammar error"),
+        }
+    }
+}
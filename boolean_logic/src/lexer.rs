@@ -0,0 +1,204 @@
+use crate::operation::Operation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Operand(String),
+    Operation(char),
+    LeftParenthesis,
+    RightParenthesis,
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lexeme {
+    pub index: usize,
+    pub token: Token,
+    pub text: String,
+}
+
+impl Lexeme {
+    pub fn symbol(&self) -> String {
+        self.text.clone()
+    }
+
+    // Operations are spelled with their canonical "!&|^>=" character
+    pub fn rpn_symbol(&self) -> String {
+        match &self.token {
+            Token::Operation(symbol) => symbol.to_string(),
+            _ => self.text.clone(),
+        }
+    }
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
+
+fn operation_token(text: &str) -> Option<Token> {
+    let operation = Operation::from_symbol(text)?;
+    let canonical = operation.to_string().chars().next()?;
+    Some(Token::Operation(canonical))
+}
+
+// When `separated` is false every character is its own token, which keeps the
+// compact "AB&" formulas working; otherwise operands are whole identifiers and
+// word or doubled operators such as "and" and "&&" are recognized
+pub fn tokenize(formula: &str, separated: bool, is_operand: fn(&str) -> bool) -> Vec<Lexeme> {
+    let characters: Vec<char> = formula.chars().collect();
+    let mut lexemes = vec![];
+    let mut index = 0;
+    while index < characters.len() {
+        let character = characters[index];
+        let mut end = index + 1;
+        let token = match character {
+            character if character.is_whitespace() => {
+                index = end;
+                continue;
+            }
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            character if is_identifier_character(character) => {
+                while separated
+                    && end < characters.len()
+                    && is_identifier_character(characters[end])
+                {
+                    end += 1;
+                }
+                let name: String = characters[index..end].iter().collect();
+                match operation_token(&name) {
+                    Some(token) if separated => token,
+                    _ if is_operand(&name) => Token::Operand(name),
+                    _ => Token::Unknown(name),
+                }
+            }
+            '\\' if separated => {
+                while end < characters.len() && characters[end].is_ascii_alphabetic() {
+                    end += 1;
+                }
+                let command: String = characters[index..end].iter().collect();
+                operation_token(&command).unwrap_or(Token::Unknown(command))
+            }
+            _ => {
+                let longest = (1..=3)
+                    .rev()
+                    .filter(|length| index + length <= characters.len())
+                    .find_map(|length| {
+                        let text: String = characters[index..index + length].iter().collect();
+                        // Doubled operators would swallow two compact operations
+                        if !separated && (text == "&&" || text == "||") {
+                            return None;
+                        }
+                        operation_token(&text).map(|token| (length, token))
+                    });
+                match longest {
+                    Some((length, token)) => {
+                        end = index + length;
+                        token
+                    }
+                    None => Token::Unknown(character.to_string()),
+                }
+            }
+        };
+        lexemes.push(Lexeme {
+            index,
+            token,
+            text: characters[index..end].iter().collect(),
+        });
+        index = end;
+    }
+    lexemes
+}
+
+// Compact output for single character operands, space separated otherwise
+pub fn to_rpn_string(lexemes: &[Lexeme]) -> String {
+    let symbols: Vec<String> = lexemes.iter().map(Lexeme::rpn_symbol).collect();
+    let separator = if symbols.iter().all(|symbol| symbol.chars().count() == 1) {
+        ""
+    } else {
+        " "
+    };
+    symbols.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(formula: &str, separated: bool) -> Vec<String> {
+        tokenize(formula, separated, |_| true)
+            .iter()
+            .map(Lexeme::rpn_symbol)
+            .collect()
+    }
+
+    #[test]
+    fn tokenize_compact_and_separated() {
+        assert_eq!(symbols("AB&", false), vec!["A", "B", "&"]);
+        assert_eq!(symbols("AB &", true), vec!["AB", "&"]);
+        assert_eq!(symbols("AB& \n", false), vec!["A", "B", "&"]);
+        assert_eq!(
+            symbols("clk_en x17& ->", true),
+            vec!["clk_en", "x17", "&", ">"]
+        );
+        assert_eq!(symbols("(a<->b)", true), vec!["(", "a", "=", "b", ")"]);
+    }
+
+    #[test]
+    fn tokenize_unknown_symbols() {
+        let lexemes = tokenize("x1 2 ?", true, |name| name.starts_with('x'));
+        assert_eq!(
+            lexemes,
+            vec![
+                Lexeme {
+                    index: 0,
+                    token: Token::Operand("x1".to_string()),
+                    text: "x1".to_string(),
+                },
+                Lexeme {
+                    index: 3,
+                    token: Token::Unknown("2".to_string()),
+                    text: "2".to_string(),
+                },
+                Lexeme {
+                    index: 5,
+                    token: Token::Unknown("?".to_string()),
+                    text: "?".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_alternative_notations() {
+        assert_eq!(
+            symbols("¬A ∧ B ∨ C ⊕ D → E ↔ F", true),
+            vec!["!", "A", "&", "B", "|", "C", "^", "D", ">", "E", "=", "F"]
+        );
+        assert_eq!(
+            symbols("not a and b or c xor d implies e iff f", true),
+            vec!["!", "a", "&", "b", "|", "c", "^", "d", ">", "e", "=", "f"]
+        );
+        assert_eq!(
+            symbols("~a && b || c -> d <-> e", true),
+            vec!["!", "a", "&", "b", "|", "c", ">", "d", "=", "e"]
+        );
+        assert_eq!(
+            symbols("\\lnot a \\land b \\rightarrow c", true),
+            vec!["!", "a", "&", "b", ">", "c"]
+        );
+        assert_eq!(symbols("AB∧C¬∨", false), vec!["A", "B", "&", "C", "!", "|"]);
+        assert_eq!(symbols("ABC&&", false), vec!["A", "B", "C", "&", "&"]);
+        let lexemes = tokenize("a implies b", true, |_| true);
+        assert_eq!(lexemes[1].index, 2);
+        assert_eq!(lexemes[1].symbol(), "implies");
+        assert_eq!(lexemes[2].index, 10);
+    }
+
+    #[test]
+    fn rpn_string_separator() {
+        let compact = tokenize("A B &", true, |_| true);
+        assert_eq!(to_rpn_string(&compact), "AB&");
+        let separated = tokenize("clk B &", true, |_| true);
+        assert_eq!(to_rpn_string(&separated), "clk B &");
+    }
+}
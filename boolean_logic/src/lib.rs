@@ -0,0 +1,11 @@
+pub mod concrete_tree;
+pub mod diagnostic;
+pub mod error;
+pub mod incremental;
+pub mod infix;
+pub mod lexer;
+pub mod operation;
+pub mod prefix;
+pub mod symbol_table;
+pub mod tree_node;
+pub mod truth_table;
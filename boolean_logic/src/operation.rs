@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+    And,
+    Or,
+    Xor,
+    Not,
+    IfThen,
+    Equality,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Notation {
+    Symbol,
+    Unicode,
+    Word,
+    CStyle,
+    Latex,
+}
+
+impl Notation {
+    pub const ALL: [Notation; 5] = [
+        Notation::Symbol,
+        Notation::Unicode,
+        Notation::Word,
+        Notation::CStyle,
+        Notation::Latex,
+    ];
+}
+
+impl Operation {
+    pub const ALL: [Operation; 6] = [
+        Operation::And,
+        Operation::Or,
+        Operation::Xor,
+        Operation::Not,
+        Operation::IfThen,
+        Operation::Equality,
+    ];
+
+    pub fn new(symbol: char) -> (Self, u8) {
+        match symbol {
+            '!' => (Self::Not, 1),
+            '&' => (Self::And, 2),
+            '|' => (Self::Or, 2),
+            '^' => (Self::Xor, 2),
+            '>' => (Self::IfThen, 2),
+            '=' => (Self::Equality, 2),
+            _ => panic!("Unknown Symbol should have been seen earlier"),
+        }
+    }
+    pub fn symbol(&self, notation: Notation) -> &'static str {
+        match notation {
+            Notation::Symbol => match self {
+                Operation::Equality => "=",
+                Operation::Not => "!",
+                Operation::And => "&",
+                Operation::Or => "|",
+                Operation::Xor => "^",
+                Operation::IfThen => ">",
+            },
+            Notation::Unicode => match self {
+                Operation::Equality => "↔",
+                Operation::Not => "¬",
+                Operation::And => "∧",
+                Operation::Or => "∨",
+                Operation::Xor => "⊕",
+                Operation::IfThen => "→",
+            },
+            Notation::Word => match self {
+                Operation::Equality => "iff",
+                Operation::Not => "not",
+                Operation::And => "and",
+                Operation::Or => "or",
+                Operation::Xor => "xor",
+                Operation::IfThen => "implies",
+            },
+            Notation::CStyle => match self {
+                Operation::Equality => "<->",
+                Operation::Not => "~",
+                Operation::And => "&&",
+                Operation::Or => "||",
+                Operation::Xor => "^",
+                Operation::IfThen => "->",
+            },
+            Notation::Latex => match self {
+                Operation::Equality => "\\leftrightarrow",
+                Operation::Not => "\\lnot",
+                Operation::And => "\\land",
+                Operation::Or => "\\lor",
+                Operation::Xor => "\\oplus",
+                Operation::IfThen => "\\rightarrow",
+            },
+        }
+    }
+
+    // Binding power and right associativity, from the loosest (equality) to
+    // the tightest (not)
+    pub fn precedence(&self) -> (u8, bool) {
+        match self {
+            Operation::Equality => (1, false),
+            Operation::IfThen => (2, true),
+            Operation::Or => (3, false),
+            Operation::Xor => (4, false),
+            Operation::And => (5, false),
+            Operation::Not => (6, true),
+        }
+    }
+
+    // Accepts the spelling of any notation
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|operation| {
+            Notation::ALL
+                .iter()
+                .any(|&notation| operation.symbol(notation) == symbol)
+        })
+    }
+
+    pub fn get_operation_closure(&self) -> impl FnOnce(bool, bool) -> bool {
+        match self {
+            Operation::Equality => |first, second| first == second,
+            Operation::Not => |first: bool, _| !first,
+            Operation::And => |first, second| first & second,
+            Operation::Or => |first, second| first | second,
+            Operation::Xor => |first, second| first ^ second,
+            Operation::IfThen => |first: bool, second| !first || second,
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol(Notation::Symbol))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn symbols_round_trip_in_every_notation() {
+        for operation in Operation::ALL {
+            for notation in Notation::ALL {
+                assert_eq!(
+                    Operation::from_symbol(operation.symbol(notation)),
+                    Some(operation.clone())
+                );
+            }
+            let canonical = operation.to_string().chars().next().unwrap();
+            assert_eq!(Operation::new(canonical).0, operation);
+        }
+        assert_eq!(Operation::from_symbol("∧"), Some(Operation::And));
+        assert_eq!(Operation::from_symbol("implies"), Some(Operation::IfThen));
+        assert_eq!(Operation::from_symbol("\\oplus"), Some(Operation::Xor));
+        assert_eq!(Operation::from_symbol("nand"), None);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    diagnostic::{Diagnostics, IssueKind},
+    error::Result,
+    lexer::{to_rpn_string, tokenize, Lexeme, Token},
+};
+
+struct PrefixParser {
+    lexemes: Vec<Lexeme>,
+    position: usize,
+    end_index: usize,
+    output: Vec<Lexeme>,
+    diagnostics: Diagnostics,
+}
+
+// Reorders a polish formula such as "|&AB!C" into the reverse polish lexemes
+// the tree builders already understand
+pub fn parse_prefix(
+    input: &str,
+    separated: bool,
+    is_operand: fn(&str) -> bool,
+) -> Result<Vec<Lexeme>> {
+    let mut diagnostics = Diagnostics::new(input);
+    let lexemes = tokenize(input, separated, is_operand)
+        .into_iter()
+        .filter(|lexeme| match &lexeme.token {
+            Token::Operand(_) | Token::Operation(_) => true,
+            _ => {
+                diagnostics.push(
+                    lexeme.index,
+                    Some(lexeme.symbol()),
+                    0,
+                    IssueKind::UnknownSymbol,
+                );
+                false
+            }
+        })
+        .collect();
+    let mut parser = PrefixParser {
+        lexemes,
+        position: 0,
+        end_index: input.chars().count(),
+        output: vec![],
+        diagnostics,
+    };
+    parser.parse_expression(0);
+    // Every extra complete expression is one more operand left at the top
+    let mut number_of_expressions = 1;
+    while let Some(lexeme) = parser.lexemes.get(parser.position).cloned() {
+        number_of_expressions += 1;
+        parser.diagnostics.push(
+            lexeme.index,
+            Some(lexeme.symbol()),
+            number_of_expressions,
+            IssueKind::LeftoverOperands,
+        );
+        parser.parse_expression(0);
+    }
+    parser.diagnostics.into_result()?;
+    Ok(parser.output)
+}
+
+pub fn prefix_to_rpn(input: &str, separated: bool, is_operand: fn(&str) -> bool) -> Result<String> {
+    Ok(to_rpn_string(&parse_prefix(input, separated, is_operand)?))
+}
+
+impl PrefixParser {
+    fn parse_expression(&mut self, depth: usize) {
+        let Some(lexeme) = self.lexemes.get(self.position).cloned() else {
+            self.diagnostics
+                .push(self.end_index, None, depth, IssueKind::ExpectedOperand);
+            return;
+        };
+        self.position += 1;
+        if let Token::Operation(symbol) = lexeme.token {
+            let expected: u8 = if symbol == '!' { 1 } else { 2 };
+            for found in 0..expected as usize {
+                if self.position == self.lexemes.len() {
+                    self.diagnostics.push(
+                        lexeme.index,
+                        Some(lexeme.symbol()),
+                        depth,
+                        IssueKind::MissingOperands { expected, found },
+                    );
+                    break;
+                }
+                self.parse_expression(depth + 1);
+            }
+        }
+        self.output.push(lexeme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostic::FormulaIssue, error::Error};
+
+    fn to_rpn(input: &str) -> Result<String> {
+        prefix_to_rpn(input, false, is_variable)
+    }
+
+    fn is_variable(name: &str) -> bool {
+        name.chars()
+            .all(|character| character.is_ascii_alphabetic())
+    }
+
+    fn issues(input: &str) -> Vec<(usize, IssueKind)> {
+        match to_rpn(input) {
+            Err(Error::InvalidFormulaSyntax(diagnostics))
+            | Err(Error::InvalidFormulaGrammar(diagnostics)) => diagnostics
+                .issues
+                .into_iter()
+                .map(|FormulaIssue { index, kind, .. }| (index, kind))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn prefix_to_rpn_ok() {
+        assert_eq!(to_rpn("A").unwrap(), "A");
+        assert_eq!(to_rpn("&AB").unwrap(), "AB&");
+        assert_eq!(to_rpn("|&AB!C").unwrap(), "AB&C!|");
+        assert_eq!(to_rpn(">=AB^CD").unwrap(), "AB=CD^>");
+        assert_eq!(
+            prefix_to_rpn("and clk not reset", true, is_variable).unwrap(),
+            "clk reset ! &"
+        );
+        assert_eq!(to_rpn("∧ A ∨ B C").unwrap(), "ABC|&");
+    }
+
+    #[test]
+    fn prefix_to_rpn_reports_every_issue() {
+        assert!(matches!(
+            to_rpn("&A?B"),
+            Err(Error::InvalidFormulaSyntax(_))
+        ));
+        assert_eq!(issues(""), vec![(0, IssueKind::ExpectedOperand)]);
+        assert_eq!(
+            issues("&A"),
+            vec![(
+                0,
+                IssueKind::MissingOperands {
+                    expected: 2,
+                    found: 1
+                }
+            )]
+        );
+        assert_eq!(
+            issues("|&A!"),
+            vec![
+                (
+                    0,
+                    IssueKind::MissingOperands {
+                        expected: 2,
+                        found: 1
+                    }
+                ),
+                (
+                    3,
+                    IssueKind::MissingOperands {
+                        expected: 1,
+                        found: 0
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            issues("&ABC!D"),
+            vec![
+                (3, IssueKind::LeftoverOperands),
+                (4, IssueKind::LeftoverOperands)
+            ]
+        );
+    }
+}
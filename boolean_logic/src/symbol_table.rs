@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+// Maps variable names to dense indices in order of first appearance
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolTable {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        &self.names[index]
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn symbol_table_dense_indices() {
+        let mut symbols = SymbolTable::new();
+        assert!(symbols.is_empty());
+        assert_eq!(symbols.insert("clk_en"), 0);
+        assert_eq!(symbols.insert("x17"), 1);
+        assert_eq!(symbols.insert("clk_en"), 0);
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols.index("x17"), Some(1));
+        assert_eq!(symbols.index("y"), None);
+        assert_eq!(symbols.name(1), "x17");
+        assert_eq!(symbols.names(), ["clk_en", "x17"]);
+    }
+}
@@ -1,15 +1,16 @@
 use crate::{
     error::{Error, Result},
-    operation::Operation,
+    operation::{Notation, Operation},
+    symbol_table::SymbolTable,
 };
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum NodeType {
-    Leaf(char),
+    Leaf(usize),
     Node(Operation),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TreeNode {
     pub node_type: NodeType,
     left_child: Option<Box<TreeNode>>,
@@ -24,23 +25,14 @@ impl NodeType {
         }
     }
     pub fn is_negation(&self) -> bool {
-        match self {
-            NodeType::Node(Operation::Not) => true,
-            _ => false,
-        }
+        matches!(self, NodeType::Node(Operation::Not))
     }
     pub fn is_or(&self) -> bool {
-        match self {
-            NodeType::Node(Operation::Or) => true,
-            _ => false,
-        }
+        matches!(self, NodeType::Node(Operation::Or))
     }
 
     pub fn is_and(&self) -> bool {
-        match self {
-            NodeType::Node(Operation::And) => true,
-            _ => false,
-        }
+        matches!(self, NodeType::Node(Operation::And))
     }
 }
 
@@ -54,12 +46,12 @@ impl TreeNode {
             NodeType::Leaf(_) => {}
             NodeType::Node(Operation::Not) => {
                 if left_child.is_none() {
-                    return Err(Error::InvalidFormulaGrammar);
+                    return Err(Error::MissingChildNode);
                 }
             }
             NodeType::Node(_) => {
                 if left_child.is_none() || right_child.is_none() {
-                    return Err(Error::InvalidFormulaGrammar);
+                    return Err(Error::MissingChildNode);
                 }
             }
         }
@@ -70,6 +62,43 @@ impl TreeNode {
         })
     }
 
+    pub fn left_child(&self) -> Option<&TreeNode> {
+        self.left_child.as_deref()
+    }
+
+    pub fn right_child(&self) -> Option<&TreeNode> {
+        self.right_child.as_deref()
+    }
+
+    pub fn resolve_node(&self, values: &[bool]) -> bool {
+        let operation_closure = match &self.node_type {
+            NodeType::Leaf(_) => panic!("resolve node should never be call on a leaf"),
+            NodeType::Node(operation) => operation.get_operation_closure(),
+        };
+        let left_value;
+        let right_value;
+        if let Some(left_child) = &self.left_child {
+            left_value = match left_child.node_type {
+                NodeType::Leaf(var) => values[var],
+                NodeType::Node(_) => left_child.resolve_node(values),
+            }
+        } else {
+            panic!("A left_child should be present at this point");
+        }
+        if let Some(right_child) = &self.right_child {
+            right_value = match right_child.node_type {
+                NodeType::Leaf(var) => values[var],
+                NodeType::Node(_) => right_child.resolve_node(values),
+            }
+        } else {
+            right_value = match self.node_type {
+                NodeType::Node(Operation::Not) => false,
+                _ => panic!("A right_child should be present at this point"),
+            }
+        }
+        operation_closure(left_value, right_value)
+    }
+
     pub fn simplify_node(&mut self) {
         if let Some(left_child) = &mut self.left_child {
             left_child.simplify_node();
@@ -259,7 +288,6 @@ impl TreeNode {
                 self.node_type = new_node_type;
                 self.left_child = new_left_child;
                 self.right_child = new_right_child;
-                return;
                 // get the grandchild op type and child and set them to self
             }
             NodeType::Node(Operation::Or) => {
@@ -277,8 +305,8 @@ impl TreeNode {
                 )
                 .unwrap();
                 self.node_type = NodeType::Node(Operation::And);
-                self.right_child = Some(new_right_child).map(Box::new);
-                self.left_child = Some(new_left_child).map(Box::new);
+                self.right_child = Some(Box::new(new_right_child));
+                self.left_child = Some(Box::new(new_left_child));
                 // do not or simplification
             }
             NodeType::Node(Operation::And) => {
@@ -296,24 +324,185 @@ impl TreeNode {
                 )
                 .unwrap();
                 self.node_type = NodeType::Node(Operation::Or);
-                self.right_child = Some(new_right_child).map(Box::new);
-                self.left_child = Some(new_left_child).map(Box::new);
+                self.right_child = Some(Box::new(new_right_child));
+                self.left_child = Some(Box::new(new_left_child));
                 // do not and simplification
             }
             _ => panic!("Other node should not be seen here"),
         }
     }
 
-    pub fn print_rpn_op_from_tree(&self) {
+    pub fn print_rpn_op_from_tree(&self, symbols: &SymbolTable, notation: Notation) {
+        print!("{}", self.to_rpn(symbols, notation));
+    }
+
+    pub fn to_rpn(&self, symbols: &SymbolTable, notation: Notation) -> String {
+        let mut output = vec![];
+        self.collect_rpn_symbols(symbols, notation, &mut output);
+        join_symbols(&output)
+    }
+
+    pub fn to_prefix(&self, symbols: &SymbolTable, notation: Notation) -> String {
+        let mut output = vec![];
+        self.collect_prefix_symbols(symbols, notation, &mut output);
+        join_symbols(&output)
+    }
+
+    pub fn to_infix(&self, symbols: &SymbolTable, notation: Notation) -> String {
+        let mut output = String::new();
+        self.write_infix(symbols, notation, &mut output);
+        output
+    }
+
+    // Breaks the top level chain of the root operation, so a large CNF gets
+    // one or more clauses per line
+    pub fn to_infix_wrapped(
+        &self,
+        symbols: &SymbolTable,
+        notation: Notation,
+        width: usize,
+    ) -> String {
+        let operation = match &self.node_type {
+            NodeType::Node(Operation::Not) | NodeType::Leaf(_) => {
+                return self.to_infix(symbols, notation)
+            }
+            NodeType::Node(operation) => operation,
+        };
+        let mut terms = vec![];
+        self.collect_chain(operation, &mut terms);
+        let symbol = operation.symbol(notation);
+        let mut lines = vec![];
+        let mut line = String::new();
+        for (index, (term, is_left)) in terms.iter().enumerate() {
+            let mut text = String::new();
+            term.write_child(operation, *is_left, symbols, notation, &mut text);
+            if index == 0 {
+                line = text;
+            } else if line.chars().count() + symbol.chars().count() + text.chars().count() + 2
+                > width
+            {
+                lines.push(line);
+                line = format!("  {symbol} {text}");
+            } else {
+                line = format!("{line} {symbol} {text}");
+            }
+        }
+        lines.push(line);
+        lines.join("\n")
+    }
+
+    // Only the side the operation groups towards continues the chain, the
+    // other side keeps its parentheses so the tree shape survives a re-parse
+    fn collect_chain<'a>(&'a self, operation: &Operation, terms: &mut Vec<(&'a TreeNode, bool)>) {
+        let (_, right_associative) = operation.precedence();
+        for (child, is_left) in [(self.left_child(), true), (self.right_child(), false)] {
+            let Some(child) = child else {
+                continue;
+            };
+            let is_link = child.node_type == NodeType::Node(operation.clone());
+            if is_link && is_left != right_associative {
+                child.collect_chain(operation, terms);
+            } else {
+                terms.push((child, is_left));
+            }
+        }
+    }
+
+    fn write_infix(&self, symbols: &SymbolTable, notation: Notation, output: &mut String) {
+        let operation = match &self.node_type {
+            NodeType::Leaf(var) => {
+                output.push_str(symbols.name(*var));
+                return;
+            }
+            NodeType::Node(operation) => operation,
+        };
+        let left_child = self
+            .left_child
+            .as_ref()
+            .expect("An operation node should have a left_child");
+        let symbol = operation.symbol(notation);
+        match &self.right_child {
+            None => {
+                output.push_str(symbol);
+                // Word and LaTeX negations would run into the operand
+                if symbol.ends_with(|character: char| character.is_ascii_alphabetic()) {
+                    output.push(' ');
+                }
+                left_child.write_child(operation, true, symbols, notation, output);
+            }
+            Some(right_child) => {
+                left_child.write_child(operation, true, symbols, notation, output);
+                output.push_str(&format!(" {symbol} "));
+                right_child.write_child(operation, false, symbols, notation, output);
+            }
+        }
+    }
+
+    fn write_child(
+        &self,
+        parent: &Operation,
+        is_left: bool,
+        symbols: &SymbolTable,
+        notation: Notation,
+        output: &mut String,
+    ) {
+        let needs_parentheses = match &self.node_type {
+            NodeType::Leaf(_) | NodeType::Node(Operation::Not) => false,
+            NodeType::Node(operation) => {
+                let (precedence, right_associative) = operation.precedence();
+                let (parent_precedence, _) = parent.precedence();
+                if precedence != parent_precedence {
+                    precedence < parent_precedence
+                } else {
+                    // Only one operation per level, so this is a chain of it
+                    // and only the side it does not group towards needs them
+                    is_left == right_associative
+                }
+            }
+        };
+        if needs_parentheses {
+            output.push('(');
+            self.write_infix(symbols, notation, output);
+            output.push(')');
+        } else {
+            self.write_infix(symbols, notation, output);
+        }
+    }
+
+    fn collect_rpn_symbols<'a>(
+        &self,
+        symbols: &'a SymbolTable,
+        notation: Notation,
+        output: &mut Vec<&'a str>,
+    ) {
+        if let Some(left_child) = &self.left_child {
+            left_child.collect_rpn_symbols(symbols, notation, output);
+        }
+        if let Some(right_child) = &self.right_child {
+            right_child.collect_rpn_symbols(symbols, notation, output);
+        }
+        output.push(self.symbol(symbols, notation));
+    }
+
+    fn collect_prefix_symbols<'a>(
+        &self,
+        symbols: &'a SymbolTable,
+        notation: Notation,
+        output: &mut Vec<&'a str>,
+    ) {
+        output.push(self.symbol(symbols, notation));
         if let Some(left_child) = &self.left_child {
-            left_child.print_rpn_op_from_tree();
+            left_child.collect_prefix_symbols(symbols, notation, output);
         }
         if let Some(right_child) = &self.right_child {
-            right_child.print_rpn_op_from_tree();
+            right_child.collect_prefix_symbols(symbols, notation, output);
         }
+    }
+
+    fn symbol<'a>(&self, symbols: &'a SymbolTable, notation: Notation) -> &'a str {
         match &self.node_type {
-            NodeType::Node(op) => print!("{op}"),
-            NodeType::Leaf(var) => print!("{var}"),
+            NodeType::Node(op) => op.symbol(notation),
+            NodeType::Leaf(var) => symbols.name(*var),
         }
     }
 
@@ -338,19 +527,19 @@ impl TreeNode {
         let new_left_child = TreeNode::build(
             NodeType::Node(Operation::And),
             origin_left_child,
-            Some(negate_right_child).map(Box::new),
+            Some(Box::new(negate_right_child)),
         )
         .expect("Nothing should fail at this point, we know the node will be valid");
 
         let new_right_child = TreeNode::build(
             NodeType::Node(Operation::And),
             origin_right_child,
-            Some(negate_left_child).map(Box::new),
+            Some(Box::new(negate_left_child)),
         )
         .expect("Nothing should fail at this point, we know the node will be valid");
 
-        self.left_child = Some(new_left_child).map(Box::new);
-        self.right_child = Some(new_right_child).map(Box::new);
+        self.left_child = Some(Box::new(new_left_child));
+        self.right_child = Some(Box::new(new_right_child));
         self.node_type = NodeType::Node(Operation::Or);
     }
 
@@ -364,7 +553,7 @@ impl TreeNode {
         )
         .expect("Nothing should fail at this point, we know the node will be valid");
 
-        self.left_child = Some(negate_left_child).map(Box::new);
+        self.left_child = Some(Box::new(negate_left_child));
         self.node_type = NodeType::Node(Operation::Or);
     }
 
@@ -393,13 +582,23 @@ impl TreeNode {
 
         let new_right_child = TreeNode::build(
             NodeType::Node(Operation::And),
-            Some(not_right_child).map(Box::new),
-            Some(not_left_child).map(Box::new),
+            Some(Box::new(not_right_child)),
+            Some(Box::new(not_left_child)),
         )
         .expect("Nothing should fail at this point, we know the node will be valid");
 
-        self.left_child = Some(new_left_child).map(Box::new);
-        self.right_child = Some(new_right_child).map(Box::new);
+        self.left_child = Some(Box::new(new_left_child));
+        self.right_child = Some(Box::new(new_right_child));
         self.node_type = NodeType::Node(Operation::Or);
     }
 }
+
+// Single character symbols keep the compact "AB&" form
+fn join_symbols(output: &[&str]) -> String {
+    let separator = if output.iter().all(|symbol| symbol.chars().count() == 1) {
+        ""
+    } else {
+        " "
+    };
+    output.join(separator)
+}
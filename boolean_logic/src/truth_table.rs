@@ -0,0 +1,564 @@
+use std::fmt::Display;
+
+use ex02::gray_code::gray_encode;
+
+use crate::{
+    diagnostic::check_rpn,
+    error::{Error, Result},
+    incremental::IncrementalEvaluator,
+    infix::parse_infix,
+    lexer::{Lexeme, Token},
+    operation::{Notation, Operation},
+    prefix::parse_prefix,
+    symbol_table::SymbolTable,
+    tree_node::{NodeType, TreeNode},
+};
+
+// Rows are numbered with a u64, the first variable being the highest bit
+const MAX_TABLE_VARIABLES: usize = 63;
+
+#[derive(Debug)]
+pub struct TruthTable {
+    root: TreeNode,
+    symbols: SymbolTable,
+}
+
+impl TruthTable {
+    // Compact formulas such as "AB&C|" read every character on its own, so
+    // variables are single uppercase letters
+    pub fn build_from_str(input: &str) -> Result<Self> {
+        Self::build_from_lexemes(check_rpn(input, false, is_compact_variable)?)
+    }
+
+    // Whitespace separated formulas such as "clk_en x17 &" take identifiers
+    // and word operators
+    pub fn build_from_separated_str(input: &str) -> Result<Self> {
+        Self::build_from_lexemes(check_rpn(input, true, is_variable)?)
+    }
+
+    pub fn build_from_infix(input: &str) -> Result<Self> {
+        Self::build_from_lexemes(parse_infix(input, is_variable)?)
+    }
+
+    pub fn build_from_prefix(input: &str) -> Result<Self> {
+        Self::build_from_lexemes(parse_prefix(input, false, is_compact_variable)?)
+    }
+
+    pub fn build_from_separated_prefix(input: &str) -> Result<Self> {
+        Self::build_from_lexemes(parse_prefix(input, true, is_variable)?)
+    }
+
+    fn build_from_lexemes(lexemes: Vec<Lexeme>) -> Result<Self> {
+        let mut stack = vec![];
+        let mut symbols = SymbolTable::new();
+        for lexeme in lexemes {
+            match lexeme.token {
+                Token::Operation(symbol) => {
+                    let new_node = Self::build_new_node(&mut stack, symbol)?;
+                    stack.push(new_node)
+                }
+                Token::Operand(name) => {
+                    let variable = symbols.insert(&name);
+                    let new_node = TreeNode::build(NodeType::Leaf(variable), None, None)?;
+                    stack.push(new_node);
+                }
+                _ => unreachable!("The parsers should have rejected other tokens"),
+            }
+        }
+        Ok(Self {
+            root: stack.pop().expect("This should not be None at this point"),
+            symbols,
+        })
+    }
+
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    // Values are given in symbol table order, one per variable
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        match self.root.node_type {
+            NodeType::Node(_) => self.root.resolve_node(values),
+            NodeType::Leaf(variable) => values[variable],
+        }
+    }
+
+    pub fn print_rpn_from_tree(&self) {
+        self.print_rpn_in_notation(Notation::Symbol);
+    }
+
+    pub fn print_rpn_in_notation(&self, notation: Notation) {
+        self.root.print_rpn_op_from_tree(&self.symbols, notation);
+        println!();
+    }
+
+    pub fn to_rpn(&self, notation: Notation) -> String {
+        self.root.to_rpn(&self.symbols, notation)
+    }
+
+    pub fn to_prefix(&self, notation: Notation) -> String {
+        self.root.to_prefix(&self.symbols, notation)
+    }
+
+    pub fn to_infix(&self, notation: Notation) -> String {
+        self.root.to_infix(&self.symbols, notation)
+    }
+
+    pub fn to_infix_wrapped(&self, notation: Notation, width: usize) -> String {
+        self.root.to_infix_wrapped(&self.symbols, notation, width)
+    }
+
+    fn build_new_node(stack: &mut Vec<TreeNode>, symbol: char) -> Result<TreeNode> {
+        let (operation, number_of_childs) = Operation::new(symbol);
+        let right_child = if number_of_childs == 2 {
+            stack.pop()
+        } else {
+            None
+        };
+        let left_child = stack.pop();
+        TreeNode::build(
+            NodeType::Node(operation),
+            right_child.map(Box::new),
+            left_child.map(Box::new),
+        )
+    }
+
+    pub fn turn_into_negation_normal_form(&mut self) {
+        self.root.simplify_node();
+        self.root.push_negation();
+    }
+    pub fn turn_into_conjuctive_normal_form(&mut self) {
+        self.turn_into_negation_normal_form();
+
+        loop {
+            let mut modified = false;
+            self.root.convert_to_conjuctive_normal_form(&mut modified);
+            if !modified {
+                break;
+            }
+        }
+    }
+
+    pub fn compute_truth_table(&self) -> Result<()> {
+        let number_of_combination = self.number_of_combination()?;
+
+        self.print_truth_table_header();
+
+        for variables_values in 0..number_of_combination {
+            let values = self.compute_values(variables_values);
+            let result = self.evaluate(&values);
+            self.print_truth_table_line(&values, result);
+        }
+        Ok(())
+    }
+
+    pub fn compute_truth_table_in_gray_order(&self) -> Result<()> {
+        self.number_of_combination()?;
+        self.print_truth_table_header();
+
+        self.visit_gray_order_rows(|_, values, result| self.print_truth_table_line(values, result))
+    }
+
+    pub fn gray_order_rows(&self) -> Result<Vec<(u64, bool)>> {
+        let mut rows = vec![];
+        self.visit_gray_order_rows(|row, _, result| rows.push((gray_encode(row), result)))?;
+        Ok(rows)
+    }
+
+    // Walks the assignments in gray code order so a single variable flips per
+    // row, and only the ancestors of its leaves are evaluated again
+    fn visit_gray_order_rows(&self, mut visit: impl FnMut(u64, &[bool], bool)) -> Result<()> {
+        let number_of_variables = self.symbols.len();
+        let number_of_combination = self.number_of_combination()?;
+        let mut evaluator = IncrementalEvaluator::build(&self.root, number_of_variables);
+
+        visit(0, evaluator.values(), evaluator.value());
+        for row in 1..number_of_combination {
+            let flipped_bit = row.trailing_zeros() as usize;
+            let result = evaluator.flip(number_of_variables - 1 - flipped_bit);
+            visit(row, evaluator.values(), result);
+        }
+        Ok(())
+    }
+
+    fn number_of_combination(&self) -> Result<u64> {
+        let number_of_variables = self.symbols.len();
+        if number_of_variables > MAX_TABLE_VARIABLES {
+            return Err(Error::TooManyVariables(number_of_variables));
+        }
+        Ok(1 << number_of_variables)
+    }
+
+    fn compute_values(&self, variables_values: u64) -> Vec<bool> {
+        let number_of_variables = self.symbols.len();
+        (0..number_of_variables)
+            .map(|index| self.get_concrete_value(variables_values, number_of_variables - 1 - index))
+            .collect()
+    }
+
+    fn get_concrete_value(&self, variables_values: u64, bit: usize) -> bool {
+        let mask = 1 << bit;
+
+        let bit_of_interest = variables_values & mask;
+
+        bit_of_interest != 0
+    }
+
+    fn print_truth_table_header(&self) {
+        for name in self.symbols.names() {
+            print!("| {name} ");
+        }
+        println!("| = |");
+        for name in self.symbols.names() {
+            print!("|{}", "-".repeat(name.chars().count() + 2));
+        }
+        println!("|---|");
+    }
+
+    fn print_truth_table_line(&self, values: &[bool], result: bool) {
+        for (name, value) in self.symbols.names().iter().zip(values) {
+            print!("| {:<width$} ", *value as u32, width = name.chars().count());
+        }
+        println!("| {} |", result as u32);
+    }
+}
+
+impl Display for TruthTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_infix(Notation::Symbol))
+    }
+}
+
+fn is_compact_variable(name: &str) -> bool {
+    let mut characters = name.chars();
+    characters
+        .next()
+        .is_some_and(|character| character.is_ascii_uppercase())
+        && characters.next().is_none()
+}
+
+fn is_variable(name: &str) -> bool {
+    let mut characters = name.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    #[test]
+    fn build_truth_table_ok() {
+        let tree = TruthTable::build_from_str("AB&");
+        assert!(tree.is_ok());
+        let tree = TruthTable::build_from_str("ABCD||=");
+        assert!(tree.is_ok());
+        assert_eq!(tree.unwrap().symbols.names(), ["A", "B", "C", "D"]);
+        let tree = TruthTable::build_from_str("A!");
+        assert!(tree.is_ok());
+    }
+
+    #[test]
+    fn evaluate_single_assignment() {
+        let tree = TruthTable::build_from_infix("reset -> !clk_en & x17").unwrap();
+        assert_eq!(tree.symbols().names(), ["reset", "clk_en", "x17"]);
+        assert!(tree.evaluate(&[false, true, false]));
+        assert!(tree.evaluate(&[true, false, true]));
+        assert!(!tree.evaluate(&[true, true, true]));
+        let leaf = TruthTable::build_from_str("A").unwrap();
+        assert!(leaf.evaluate(&[true]));
+        assert!(!leaf.evaluate(&[false]));
+    }
+
+    #[test]
+    fn compact_formula_ignores_whitespace() {
+        let reference = TruthTable::build_from_str("AB&").unwrap();
+        for formula in ["AB& ", "AB&\n", " A B &"] {
+            let tree = TruthTable::build_from_str(formula).unwrap();
+            assert_eq!(tree.root, reference.root);
+            assert_eq!(tree.symbols, reference.symbols);
+        }
+        let tree = TruthTable::build_from_str("ab&");
+        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax(_))));
+        let tree = TruthTable::build_from_str("A_&");
+        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax(_))));
+    }
+
+    #[test]
+    fn truth_table_err_invalid_characters() {
+        let tree = TruthTable::build_from_str("EAu&");
+        println!("{tree:?}");
+        assert!(matches!(tree, Err(Error::InvalidFormulaSyntax(_))));
+    }
+
+    #[test]
+    fn truth_table_err_invalid_grammar() {
+        let tree = TruthTable::build_from_str("AAA&");
+        println!("{tree:?}");
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
+        let tree = TruthTable::build_from_str("AB&CD&");
+        println!("{tree:?}");
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
+    }
+
+    #[test]
+    fn gray_order_rows_match_binary_order() {
+        let tree = TruthTable::build_from_str("AB&C|D>E^FG=!&").unwrap();
+        let rows = tree.gray_order_rows().unwrap();
+        assert_eq!(rows.len(), 128);
+        for (index, (variables_values, result)) in rows.iter().enumerate() {
+            assert_eq!(*variables_values, gray_encode(index as u64));
+            let values = tree.compute_values(*variables_values);
+            assert_eq!(*result, tree.root.resolve_node(&values));
+        }
+        tree.visit_gray_order_rows(|row, values, _| {
+            assert_eq!(values, tree.compute_values(gray_encode(row)));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn gray_order_rows_reevaluate_only_dependents() {
+        let tree = TruthTable::build_from_str("AB&CD|&").unwrap();
+        let mut evaluator = IncrementalEvaluator::build(&tree.root, tree.symbols.len());
+        evaluator.flip(tree.symbols.index("A").unwrap());
+        // A, A & B and the root
+        assert_eq!(evaluator.last_reevaluated(), 3);
+        let leaf_table = TruthTable::build_from_str("A").unwrap();
+        assert_eq!(
+            leaf_table.gray_order_rows().unwrap(),
+            vec![(0, false), (1, true)]
+        );
+    }
+
+    #[test]
+    fn build_with_identifiers() {
+        let tree = TruthTable::build_from_separated_str("clk_en x17 & reset ! |").unwrap();
+        assert_eq!(tree.symbols.names(), ["clk_en", "x17", "reset"]);
+        let infix_tree = TruthTable::build_from_infix("clk_en & x17 | !reset").unwrap();
+        assert_eq!(infix_tree.root, tree.root);
+        assert_eq!(infix_tree.symbols, tree.symbols);
+        let rows = tree.gray_order_rows().unwrap();
+        // 011 in gray order: x17 and reset are set
+        assert_eq!(rows[2], (0b011, false));
+        let tree = TruthTable::build_from_separated_str("x1 x2 & x2!");
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
+    }
+
+    #[test]
+    fn unlimited_variable_count() {
+        let names: Vec<String> = (0..100).map(|index| format!("v{index}")).collect();
+        let formula = names.join(" & ");
+        let tree = TruthTable::build_from_infix(&formula).unwrap();
+        assert_eq!(tree.symbols.len(), 100);
+        assert_eq!(tree.symbols.index("v99"), Some(99));
+        assert!(matches!(
+            tree.compute_truth_table(),
+            Err(Error::TooManyVariables(100))
+        ));
+        let mut evaluator = IncrementalEvaluator::build(&tree.root, tree.symbols.len());
+        for variable in 0..100 {
+            assert_eq!(evaluator.flip(variable), variable == 99);
+        }
+    }
+
+    #[test]
+    fn build_from_alternative_notations() {
+        let reference = TruthTable::build_from_infix("!A & B | C ^ D > E = F").unwrap();
+        for formula in [
+            "¬A ∧ B ∨ C ⊕ D → E ↔ F",
+            "not A and B or C xor D implies E iff F",
+            "~A && B || C ^ D -> E <-> F",
+            "\\lnot A \\land B \\lor C \\oplus D \\rightarrow E \\leftrightarrow F",
+        ] {
+            let tree = TruthTable::build_from_infix(formula).unwrap();
+            assert_eq!(tree.root, reference.root);
+        }
+        let tree = TruthTable::build_from_separated_str("A B ∧ C ¬ or").unwrap();
+        let reference = TruthTable::build_from_str("AB&C!|").unwrap();
+        assert_eq!(tree.root, reference.root);
+        let tree = TruthTable::build_from_infix("A and");
+        assert!(matches!(tree, Err(Error::InvalidFormulaGrammar(_))));
+    }
+
+    const ALL_OPERATIONS: [&str; 6] = ["!", "&", "|", "^", ">", "="];
+
+    // Small xorshift generator so the property test stays deterministic
+    struct FormulaGenerator {
+        state: u64,
+        operations: &'static [&'static str],
+    }
+
+    impl FormulaGenerator {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 7;
+            self.state ^= self.state << 17;
+            self.state % bound
+        }
+
+        fn rpn_formula(&mut self, depth: usize, names: &[&str], output: &mut Vec<String>) {
+            if depth == 0 || self.next(4) == 0 {
+                output.push(names[self.next(names.len() as u64) as usize].to_string());
+                return;
+            }
+            let symbol = self.operations[self.next(self.operations.len() as u64) as usize];
+            self.rpn_formula(depth - 1, names, output);
+            if symbol != "!" {
+                self.rpn_formula(depth - 1, names, output);
+            }
+            output.push(symbol.to_string());
+        }
+    }
+
+    #[test]
+    fn rpn_to_prefix_round_trip() {
+        let mut generator = FormulaGenerator {
+            state: 0x2545_f491_4f6c_dd1d,
+            operations: &ALL_OPERATIONS,
+        };
+        let name_sets: [&[&str]; 2] = [&["A", "B", "C", "D"], &["clk_en", "x17", "reset", "Q"]];
+        for case in 0..500 {
+            let names = name_sets[case % 2];
+            let mut output = vec![];
+            generator.rpn_formula(6, names, &mut output);
+            let rpn = output.join(" ");
+            let tree = TruthTable::build_from_separated_str(&rpn).unwrap();
+            for notation in Notation::ALL {
+                let prefix = tree.to_prefix(notation);
+                // The printer only drops the spaces when every symbol is a
+                // single character
+                let compact = !prefix.contains(' ')
+                    && tree.symbols.names().iter().all(|name| name.len() == 1);
+                let round_trip = if compact {
+                    TruthTable::build_from_prefix(&prefix)
+                } else {
+                    TruthTable::build_from_separated_prefix(&prefix)
+                }
+                .unwrap();
+                assert_eq!(round_trip.root, tree.root, "{rpn} -> {prefix}");
+                assert_eq!(round_trip.symbols, tree.symbols);
+                assert_eq!(
+                    round_trip.to_rpn(Notation::Symbol),
+                    tree.to_rpn(Notation::Symbol)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_printer() {
+        let tree = TruthTable::build_from_str("AB&C!|").unwrap();
+        assert_eq!(tree.to_prefix(Notation::Symbol), "|&AB!C");
+        assert_eq!(tree.to_prefix(Notation::Unicode), "∨∧AB¬C");
+        assert_eq!(tree.to_prefix(Notation::Word), "or and A B not C");
+        assert_eq!(tree.to_rpn(Notation::CStyle), "A B && C ~ ||");
+        let tree = TruthTable::build_from_separated_str("clk_en").unwrap();
+        assert_eq!(tree.to_prefix(Notation::Symbol), "clk_en");
+        let tree = TruthTable::build_from_separated_prefix("and clk_en not reset").unwrap();
+        assert_eq!(tree.to_rpn(Notation::Symbol), "clk_en reset ! &");
+    }
+
+    #[test]
+    fn infix_printer_minimal_parentheses() {
+        let printed = |rpn: &str| TruthTable::build_from_str(rpn).unwrap().to_string();
+        assert_eq!(printed("A"), "A");
+        assert_eq!(printed("AB&C|"), "A & B | C");
+        assert_eq!(printed("ABC|&"), "A & (B | C)");
+        assert_eq!(printed("AB&C&"), "A & B & C");
+        assert_eq!(printed("ABC&&"), "A & (B & C)");
+        assert_eq!(printed("ABC==D="), "A = (B = C) = D");
+        assert_eq!(printed("AB>C>"), "(A > B) > C");
+        assert_eq!(printed("ABC>>"), "A > B > C");
+        assert_eq!(printed("AB&!A!!|"), "!(A & B) | !!A");
+        assert_eq!(printed("AB=CD^>"), "(A = B) > C ^ D");
+        let tree = TruthTable::build_from_str("AB|C!&").unwrap();
+        assert_eq!(tree.to_infix(Notation::Unicode), "(A ∨ B) ∧ ¬C");
+        assert_eq!(tree.to_infix(Notation::Word), "(A or B) and not C");
+        assert_eq!(
+            tree.to_infix(Notation::Latex),
+            "(A \\lor B) \\land \\lnot C"
+        );
+    }
+
+    #[test]
+    fn infix_printer_round_trip() {
+        let mut generator = FormulaGenerator {
+            state: 0x9e37_79b9_7f4a_7c15,
+            operations: &ALL_OPERATIONS,
+        };
+        for _ in 0..300 {
+            let mut output = vec![];
+            generator.rpn_formula(6, &["A", "B", "C", "D"], &mut output);
+            let tree = TruthTable::build_from_str(&output.join("")).unwrap();
+            for notation in Notation::ALL {
+                let infix = tree.to_infix(notation);
+                let round_trip = TruthTable::build_from_infix(&infix).unwrap();
+                assert_eq!(round_trip.symbols, tree.symbols, "{infix}");
+                assert_eq!(round_trip.root, tree.root, "{infix}");
+                assert_eq!(
+                    round_trip.gray_order_rows().unwrap(),
+                    tree.gray_order_rows().unwrap(),
+                    "{infix}"
+                );
+                assert_eq!(round_trip.to_infix(notation), infix);
+            }
+        }
+    }
+
+    // Without associative operators nothing can be regrouped, so the parsed
+    // tree has to be the printed one
+    #[test]
+    fn infix_printer_keeps_non_associative_structure() {
+        let mut generator = FormulaGenerator {
+            state: 0xd1b5_4a32_d192_ed03,
+            operations: &["!", ">"],
+        };
+        for _ in 0..300 {
+            let mut output = vec![];
+            generator.rpn_formula(6, &["A", "B", "C", "D"], &mut output);
+            let tree = TruthTable::build_from_str(&output.join("")).unwrap();
+            let infix = tree.to_string();
+            let round_trip = TruthTable::build_from_infix(&infix).unwrap();
+            assert_eq!(round_trip.root, tree.root, "{infix}");
+        }
+        for rpn in ["AB&!", "AB>!C>", "AB|!CD^!>", "ABC>>!D>"] {
+            let tree = TruthTable::build_from_str(rpn).unwrap();
+            let round_trip = TruthTable::build_from_infix(&tree.to_string()).unwrap();
+            assert_eq!(round_trip.root, tree.root, "{tree}");
+        }
+    }
+
+    #[test]
+    fn infix_printer_wraps_lines() {
+        let clauses: Vec<String> = (0..6)
+            .map(|index| format!("(x{index} | !y{index})"))
+            .collect();
+        let tree = TruthTable::build_from_infix(&clauses.join(" & ")).unwrap();
+        let wrapped = tree.to_infix_wrapped(Notation::Symbol, 40);
+        assert_eq!(
+            wrapped,
+            "(x0 | !y0) & (x1 | !y1) & (x2 | !y2)\n  & (x3 | !y3) & (x4 | !y4) & (x5 | !y5)"
+        );
+        assert!(wrapped.lines().all(|line| line.chars().count() <= 40));
+        let unwrapped = tree.to_infix_wrapped(Notation::Symbol, 200);
+        assert_eq!(unwrapped, tree.to_string());
+        let left_nested = TruthTable::build_from_str("AB|CD|&EF|&").unwrap();
+        assert_eq!(
+            left_nested.to_infix_wrapped(Notation::Symbol, 10),
+            "(A | B)\n  & (C | D)\n  & (E | F)"
+        );
+        let right_nested = TruthTable::build_from_str("AB|CD|EF|&&").unwrap();
+        assert_eq!(
+            right_nested.to_infix_wrapped(Notation::Symbol, 10),
+            "(A | B)\n  & ((C | D) & (E | F))"
+        );
+        let implications = TruthTable::build_from_str("AB&CD&EF&>>").unwrap();
+        assert_eq!(
+            implications.to_infix_wrapped(Notation::Symbol, 10),
+            "A & B\n  > C & D\n  > E & F"
+        );
+    }
+}
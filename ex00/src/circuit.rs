@@ -0,0 +1,260 @@
+use std::fmt;
+
+pub type WireId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    A,
+    B,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GateKind {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Node {
+    Input {
+        operand: Operand,
+        bit: u32,
+    },
+    Gate {
+        kind: GateKind,
+        inputs: (WireId, WireId),
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GateCount {
+    pub and: usize,
+    pub or: usize,
+    pub xor: usize,
+}
+
+impl GateCount {
+    pub fn total(&self) -> usize {
+        self.and + self.or + self.xor
+    }
+}
+
+// Nodes are only ever appended with inputs that already exist, so the node
+// order is a valid topological order for evaluation
+#[derive(Debug, Default)]
+pub struct Circuit {
+    nodes: Vec<Node>,
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn input(&mut self, operand: Operand, bit: u32) -> WireId {
+        self.nodes.push(Node::Input { operand, bit });
+        self.nodes.len() - 1
+    }
+
+    pub fn gate(&mut self, kind: GateKind, left: WireId, right: WireId) -> WireId {
+        assert!(
+            left < self.nodes.len() && right < self.nodes.len(),
+            "Gate inputs should be existing wires"
+        );
+        self.nodes.push(Node::Gate {
+            kind,
+            inputs: (left, right),
+        });
+        self.nodes.len() - 1
+    }
+
+    pub fn and(&mut self, left: WireId, right: WireId) -> WireId {
+        self.gate(GateKind::And, left, right)
+    }
+
+    pub fn or(&mut self, left: WireId, right: WireId) -> WireId {
+        self.gate(GateKind::Or, left, right)
+    }
+
+    pub fn xor(&mut self, left: WireId, right: WireId) -> WireId {
+        self.gate(GateKind::Xor, left, right)
+    }
+
+    pub fn evaluate(&self, a: u32, b: u32) -> Vec<bool> {
+        let mut values: Vec<bool> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let value = match *node {
+                Node::Input {
+                    operand: Operand::A,
+                    bit,
+                } => (a >> bit) & 1 == 1,
+                Node::Input {
+                    operand: Operand::B,
+                    bit,
+                } => (b >> bit) & 1 == 1,
+                Node::Gate {
+                    kind,
+                    inputs: (left, right),
+                } => match kind {
+                    GateKind::And => values[left] & values[right],
+                    GateKind::Or => values[left] | values[right],
+                    GateKind::Xor => values[left] ^ values[right],
+                },
+            };
+            values.push(value);
+        }
+        values
+    }
+
+    pub fn depths(&self) -> Vec<u32> {
+        let mut depths: Vec<u32> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let depth = match *node {
+                Node::Input { .. } => 0,
+                Node::Gate {
+                    inputs: (left, right),
+                    ..
+                } => depths[left].max(depths[right]) + 1,
+            };
+            depths.push(depth);
+        }
+        depths
+    }
+
+    pub fn gate_count(&self) -> GateCount {
+        let mut count = GateCount::default();
+        for node in &self.nodes {
+            match node {
+                Node::Input { .. } => {}
+                Node::Gate { kind, .. } => match kind {
+                    GateKind::And => count.and += 1,
+                    GateKind::Or => count.or += 1,
+                    GateKind::Xor => count.xor += 1,
+                },
+            }
+        }
+        count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitReport {
+    pub carry_in: bool,
+    pub sum: bool,
+    pub carry_out: bool,
+    pub carry_out_depth: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    pub sum: u32,
+    pub carry_out: bool,
+    pub gate_count: GateCount,
+    pub critical_path_depth: u32,
+    pub carry_chain: Vec<BitReport>,
+}
+
+// An adder netlist together with the wires holding each sum bit and the carry
+// out of each bit position
+#[derive(Debug)]
+pub struct AdderCircuit {
+    pub circuit: Circuit,
+    pub width: u32,
+    pub sum_wires: Vec<WireId>,
+    pub carry_wires: Vec<WireId>,
+}
+
+impl AdderCircuit {
+    pub fn simulate(&self, a: u32, b: u32) -> SimulationReport {
+        let values = self.circuit.evaluate(a, b);
+        let depths = self.circuit.depths();
+
+        let sum = self
+            .sum_wires
+            .iter()
+            .enumerate()
+            .fold(0, |sum, (bit, &wire)| sum | ((values[wire] as u32) << bit));
+        let carry_chain: Vec<BitReport> = self
+            .sum_wires
+            .iter()
+            .zip(&self.carry_wires)
+            .enumerate()
+            .map(|(bit, (&sum_wire, &carry_wire))| BitReport {
+                carry_in: bit > 0 && values[self.carry_wires[bit - 1]],
+                sum: values[sum_wire],
+                carry_out: values[carry_wire],
+                carry_out_depth: depths[carry_wire],
+            })
+            .collect();
+        let critical_path_depth = self
+            .sum_wires
+            .iter()
+            .chain(self.carry_wires.last())
+            .map(|&wire| depths[wire])
+            .max()
+            .unwrap_or(0);
+
+        SimulationReport {
+            sum,
+            carry_out: carry_chain.last().is_some_and(|bit| bit.carry_out),
+            gate_count: self.circuit.gate_count(),
+            critical_path_depth,
+            carry_chain,
+        }
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "| bit | cin | sum | cout | depth |")?;
+        writeln!(f, "|-----|-----|-----|------|-------|")?;
+        for (bit, report) in self.carry_chain.iter().enumerate() {
+            writeln!(
+                f,
+                "| {bit:>3} | {:>3} | {:>3} | {:>4} | {:>5} |",
+                report.carry_in as u8,
+                report.sum as u8,
+                report.carry_out as u8,
+                report.carry_out_depth
+            )?;
+        }
+        write!(
+            f,
+            "sum: {}, carry out: {}, gates: {} (and {}, or {}, xor {}), depth: {}",
+            self.sum,
+            self.carry_out as u8,
+            self.gate_count.total(),
+            self.gate_count.and,
+            self.gate_count.or,
+            self.gate_count.xor,
+            self.critical_path_depth
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_evaluate_ok() {
+        let mut circuit = Circuit::new();
+        let a = circuit.input(Operand::A, 0);
+        let b = circuit.input(Operand::B, 0);
+        let and = circuit.and(a, b);
+        let xor = circuit.xor(a, and);
+        let values = circuit.evaluate(1, 1);
+        assert_eq!(values, vec![true, true, true, false]);
+        assert_eq!(circuit.depths(), vec![0, 0, 1, 2]);
+        assert_eq!(
+            circuit.gate_count(),
+            GateCount {
+                and: 1,
+                or: 0,
+                xor: 1
+            }
+        );
+        assert!(circuit.evaluate(1, 0)[xor]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod adder;
+pub mod circuit;
+pub mod overflow;
+pub mod prefix_adder;
+pub mod ripple_carry;
@@ -1,27 +1,47 @@
+use ex00::{
+    adder::adder,
+    overflow::{checked_adder, overflowing_adder, saturating_adder, wrapping_adder},
+    prefix_adder::AdderArchitecture,
+    ripple_carry::ripple_carry_adder,
+};
+
 fn main() {
     println!("10 + 5 : {}", adder(10, 5));
     println!("0 + 15 : {}", adder(0, 15));
     println!("3 + 48 : {}", adder(3, 48));
     println!("13 + 0 : {}", adder(13, 0));
-}
-
-fn adder(a: u32, b: u32) -> u32 {
-    if b == 0 {
-        return a;
-    }
-    let carry = (a & b) << 1;
-
-    adder(a ^ b, carry)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn adder_test_ok() {
-        assert_eq!(adder(10, 5), 15);
-        assert_eq!(adder(0, 15), 15);
-        assert_eq!(adder(3, 48), 51);
-        assert_eq!(adder(13, 0), 13);
+    println!(
+        "overflowing 0xFFFFFFFF + 1 : {:?}",
+        overflowing_adder(0xFFFF_FFFF, 1)
+    );
+    println!(
+        "wrapping 0xFFFFFFFF + 1 : {}",
+        wrapping_adder(0xFFFF_FFFF, 1)
+    );
+    println!(
+        "checked 0xFFFFFFFF + 1 : {:?}",
+        checked_adder(0xFFFF_FFFF, 1)
+    );
+    println!(
+        "saturating 0xFFFFFFFF + 1 : {}",
+        saturating_adder(0xFFFF_FFFF, 1)
+    );
+    println!(
+        "{}",
+        ripple_carry_adder(8).simulate(0b1011_0110, 0b0110_1011)
+    );
+    for architecture in [
+        AdderArchitecture::RippleCarry,
+        AdderArchitecture::CarryLookahead,
+        AdderArchitecture::KoggeStone,
+        AdderArchitecture::BrentKung,
+    ] {
+        let report = architecture.build(32).simulate(0xDEAD_BEEF, 0xCAFE_BABE);
+        println!(
+            "{architecture:?} : sum {}, gates {}, depth {}",
+            report.sum,
+            report.gate_count.total(),
+            report.critical_path_depth
+        );
     }
 }
@@ -0,0 +1,82 @@
+const HIGHEST_BIT: u32 = 1 << (u32::BITS - 1);
+
+pub fn overflowing_adder(a: u32, b: u32) -> (u32, bool) {
+    if b == 0 {
+        return (a, false);
+    }
+    // The carry about to be shifted out of bit 31 is the carry-out of the whole addition
+    let carry_out = (a & b) & HIGHEST_BIT != 0;
+    let carry = (a & b) << 1;
+
+    let (sum, overflowed) = overflowing_adder(a ^ b, carry);
+    (sum, carry_out || overflowed)
+}
+
+pub fn wrapping_adder(a: u32, b: u32) -> u32 {
+    overflowing_adder(a, b).0
+}
+
+pub fn checked_adder(a: u32, b: u32) -> Option<u32> {
+    match overflowing_adder(a, b) {
+        (sum, false) => Some(sum),
+        (_, true) => None,
+    }
+}
+
+pub fn saturating_adder(a: u32, b: u32) -> u32 {
+    match overflowing_adder(a, b) {
+        (sum, false) => sum,
+        (_, true) => u32::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EDGE_VALUES: [u32; 8] = [
+        0,
+        1,
+        2,
+        0x7FFF_FFFF,
+        0x8000_0000,
+        0xFFFF_FFFE,
+        0xFFFF_FFFF,
+        0xDEAD_BEEF,
+    ];
+
+    #[test]
+    fn overflowing_adder_test_ok() {
+        assert_eq!(overflowing_adder(10, 5), (15, false));
+        assert_eq!(overflowing_adder(0xFFFF_FFFF, 1), (0, true));
+        assert_eq!(overflowing_adder(0x8000_0000, 0x8000_0000), (0, true));
+        assert_eq!(
+            overflowing_adder(0xFFFF_FFFF, 0xFFFF_FFFF),
+            (0xFFFF_FFFE, true)
+        );
+    }
+
+    #[test]
+    fn checked_adder_test_ok() {
+        assert_eq!(checked_adder(3, 48), Some(51));
+        assert_eq!(checked_adder(0xFFFF_FFFF, 1), None);
+    }
+
+    #[test]
+    fn saturating_adder_test_ok() {
+        assert_eq!(saturating_adder(13, 0), 13);
+        assert_eq!(saturating_adder(0xFFFF_FFF0, 0x20), u32::MAX);
+    }
+
+    #[test]
+    fn adder_variants_match_std() {
+        for a in EDGE_VALUES {
+            for b in EDGE_VALUES {
+                assert_eq!(overflowing_adder(a, b), a.overflowing_add(b));
+                assert_eq!(wrapping_adder(a, b), a.wrapping_add(b));
+                assert_eq!(checked_adder(a, b), a.checked_add(b));
+                assert_eq!(saturating_adder(a, b), a.saturating_add(b));
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    circuit::{AdderCircuit, Circuit, GateKind, Operand, WireId},
+    ripple_carry::ripple_carry_adder,
+};
+
+const CARRY_LOOKAHEAD_BLOCK: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdderArchitecture {
+    RippleCarry,
+    CarryLookahead,
+    KoggeStone,
+    BrentKung,
+}
+
+impl AdderArchitecture {
+    pub fn build(&self, width: u32) -> AdderCircuit {
+        match self {
+            AdderArchitecture::RippleCarry => ripple_carry_adder(width),
+            AdderArchitecture::CarryLookahead => carry_lookahead_adder(width),
+            AdderArchitecture::KoggeStone => kogge_stone_adder(width),
+            AdderArchitecture::BrentKung => brent_kung_adder(width),
+        }
+    }
+}
+
+// Bitwise generate (a & b) and propagate (a ^ b) signals every architecture starts from
+struct GeneratePropagate {
+    circuit: Circuit,
+    generate: Vec<WireId>,
+    propagate: Vec<WireId>,
+}
+
+impl GeneratePropagate {
+    fn new(width: u32) -> Self {
+        assert!(
+            (1..=u32::BITS).contains(&width),
+            "Adder width should be between 1 and 32 bits"
+        );
+        let mut circuit = Circuit::new();
+        let mut generate = Vec::with_capacity(width as usize);
+        let mut propagate = Vec::with_capacity(width as usize);
+        for bit in 0..width {
+            let a = circuit.input(Operand::A, bit);
+            let b = circuit.input(Operand::B, bit);
+            generate.push(circuit.and(a, b));
+            propagate.push(circuit.xor(a, b));
+        }
+        Self {
+            circuit,
+            generate,
+            propagate,
+        }
+    }
+
+    // carry_wires[i] holds the carry out of bit i, so sum i is p[i] ^ carry[i - 1]
+    fn into_adder(mut self, carry_wires: Vec<WireId>) -> AdderCircuit {
+        let mut sum_wires = vec![self.propagate[0]];
+        for bit in 1..self.propagate.len() {
+            sum_wires.push(self.circuit.xor(self.propagate[bit], carry_wires[bit - 1]));
+        }
+        AdderCircuit {
+            circuit: self.circuit,
+            width: sum_wires.len() as u32,
+            sum_wires,
+            carry_wires,
+        }
+    }
+}
+
+fn balanced_tree(circuit: &mut Circuit, kind: GateKind, wires: &[WireId]) -> WireId {
+    match wires {
+        [] => panic!("A gate tree needs at least one wire"),
+        [wire] => *wire,
+        _ => {
+            let (left, right) = wires.split_at(wires.len() / 2);
+            let left = balanced_tree(circuit, kind, left);
+            let right = balanced_tree(circuit, kind, right);
+            circuit.gate(kind, left, right)
+        }
+    }
+}
+
+// Block carry-lookahead: inside each block every carry is a flat sum of
+// products of the block's generate/propagate signals, blocks ripple together
+pub fn carry_lookahead_adder(width: u32) -> AdderCircuit {
+    let mut signals = GeneratePropagate::new(width);
+    let mut carry_wires: Vec<WireId> = Vec::with_capacity(width as usize);
+
+    for block_start in (0..width as usize).step_by(CARRY_LOOKAHEAD_BLOCK) {
+        let block_carry_in = carry_wires.last().copied();
+        let block_end = (block_start + CARRY_LOOKAHEAD_BLOCK).min(width as usize);
+        for bit in block_start..block_end {
+            let mut terms = vec![];
+            for source in (block_start..=bit).rev() {
+                let mut factors = signals.propagate[source + 1..=bit].to_vec();
+                factors.push(signals.generate[source]);
+                terms.push(balanced_tree(&mut signals.circuit, GateKind::And, &factors));
+            }
+            if let Some(carry_in) = block_carry_in {
+                let mut factors = signals.propagate[block_start..=bit].to_vec();
+                factors.push(carry_in);
+                terms.push(balanced_tree(&mut signals.circuit, GateKind::And, &factors));
+            }
+            carry_wires.push(balanced_tree(&mut signals.circuit, GateKind::Or, &terms));
+        }
+    }
+    signals.into_adder(carry_wires)
+}
+
+// Group generate/propagate of the bit range [group_start[i], i] for every bit i
+struct PrefixNetwork {
+    signals: GeneratePropagate,
+    group_generate: Vec<WireId>,
+    group_propagate: Vec<WireId>,
+    group_start: Vec<usize>,
+}
+
+impl PrefixNetwork {
+    fn new(width: u32) -> Self {
+        let signals = GeneratePropagate::new(width);
+        Self {
+            group_generate: signals.generate.clone(),
+            group_propagate: signals.propagate.clone(),
+            group_start: (0..width as usize).collect(),
+            signals,
+        }
+    }
+
+    // Merges the group ending at `low` into the adjacent group ending at `high`
+    fn combine(&mut self, high: usize, low: usize) {
+        assert_eq!(
+            self.group_start[high],
+            low + 1,
+            "Only adjacent groups can be combined"
+        );
+        let circuit = &mut self.signals.circuit;
+        let carried = circuit.and(self.group_propagate[high], self.group_generate[low]);
+        self.group_generate[high] = circuit.or(self.group_generate[high], carried);
+        // A group reaching bit 0 only needs its generate signal from now on
+        if self.group_start[low] > 0 {
+            self.group_propagate[high] =
+                circuit.and(self.group_propagate[high], self.group_propagate[low]);
+        }
+        self.group_start[high] = self.group_start[low];
+    }
+
+    fn into_adder(self) -> AdderCircuit {
+        self.signals.into_adder(self.group_generate)
+    }
+}
+
+pub fn kogge_stone_adder(width: u32) -> AdderCircuit {
+    let mut network = PrefixNetwork::new(width);
+    let width = width as usize;
+    let mut distance = 1;
+    while distance < width {
+        // Walk downwards so every combine still sees the previous level's groups
+        for bit in (distance..width).rev() {
+            network.combine(bit, bit - distance);
+        }
+        distance <<= 1;
+    }
+    network.into_adder()
+}
+
+pub fn brent_kung_adder(width: u32) -> AdderCircuit {
+    let mut network = PrefixNetwork::new(width);
+    let width = width as usize;
+    let mut distance = 1;
+    while distance < width {
+        for bit in (2 * distance - 1..width).step_by(2 * distance) {
+            network.combine(bit, bit - distance);
+        }
+        distance <<= 1;
+    }
+    while distance > 1 {
+        distance >>= 1;
+        for bit in (3 * distance - 1..width).step_by(2 * distance) {
+            network.combine(bit, bit - distance);
+        }
+    }
+    network.into_adder()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adder::adder, overflow::overflowing_adder};
+
+    const ARCHITECTURES: [AdderArchitecture; 4] = [
+        AdderArchitecture::RippleCarry,
+        AdderArchitecture::CarryLookahead,
+        AdderArchitecture::KoggeStone,
+        AdderArchitecture::BrentKung,
+    ];
+
+    #[test]
+    fn prefix_adders_match_adder() {
+        for architecture in ARCHITECTURES {
+            let circuit = architecture.build(32);
+            for (a, b) in [
+                (10, 5),
+                (0, 15),
+                (3, 48),
+                (13, 0),
+                (u32::MAX, 1),
+                (0xDEAD_BEEF, 0xCAFE_BABE),
+                (0x7FFF_FFFF, 0x7FFF_FFFF),
+            ] {
+                let report = circuit.simulate(a, b);
+                assert_eq!(report.sum, adder(a, b), "{architecture:?}");
+                assert_eq!(
+                    report.carry_out,
+                    overflowing_adder(a, b).1,
+                    "{architecture:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_adders_exhaustive_small_widths() {
+        for architecture in ARCHITECTURES {
+            for width in 1..=6 {
+                let circuit = architecture.build(width);
+                let mask = (1 << width) - 1;
+                for a in 0..=mask {
+                    for b in 0..=mask {
+                        let report = circuit.simulate(a, b);
+                        assert_eq!(report.sum, adder(a, b) & mask, "{architecture:?}");
+                        assert_eq!(report.carry_out, adder(a, b) > mask, "{architecture:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_adders_trade_offs() {
+        let report = |architecture: AdderArchitecture| architecture.build(32).simulate(0, 0);
+        let ripple = report(AdderArchitecture::RippleCarry);
+        let lookahead = report(AdderArchitecture::CarryLookahead);
+        let kogge_stone = report(AdderArchitecture::KoggeStone);
+        let brent_kung = report(AdderArchitecture::BrentKung);
+
+        assert!(lookahead.critical_path_depth < ripple.critical_path_depth);
+        assert!(brent_kung.critical_path_depth < lookahead.critical_path_depth);
+        assert!(kogge_stone.critical_path_depth < brent_kung.critical_path_depth);
+        assert!(brent_kung.gate_count.total() < kogge_stone.gate_count.total());
+        // The generate layer followed by one and-or cell per log2(32) prefix levels
+        assert_eq!(kogge_stone.critical_path_depth, 1 + 2 * 5);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::circuit::{AdderCircuit, Circuit, Operand, WireId};
+
+fn half_adder(circuit: &mut Circuit, a: WireId, b: WireId) -> (WireId, WireId) {
+    (circuit.xor(a, b), circuit.and(a, b))
+}
+
+fn full_adder(circuit: &mut Circuit, a: WireId, b: WireId, carry_in: WireId) -> (WireId, WireId) {
+    let (partial_sum, first_carry) = half_adder(circuit, a, b);
+    let (sum, second_carry) = half_adder(circuit, partial_sum, carry_in);
+    (sum, circuit.or(first_carry, second_carry))
+}
+
+pub fn ripple_carry_adder(width: u32) -> AdderCircuit {
+    assert!(
+        (1..=u32::BITS).contains(&width),
+        "Adder width should be between 1 and 32 bits"
+    );
+    let mut circuit = Circuit::new();
+    let mut sum_wires = Vec::with_capacity(width as usize);
+    let mut carry_wires: Vec<WireId> = Vec::with_capacity(width as usize);
+
+    for bit in 0..width {
+        let a = circuit.input(Operand::A, bit);
+        let b = circuit.input(Operand::B, bit);
+        let (sum, carry) = match carry_wires.last() {
+            None => half_adder(&mut circuit, a, b),
+            Some(&carry_in) => full_adder(&mut circuit, a, b, carry_in),
+        };
+        sum_wires.push(sum);
+        carry_wires.push(carry);
+    }
+
+    AdderCircuit {
+        circuit,
+        width,
+        sum_wires,
+        carry_wires,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adder::adder, overflow::overflowing_adder};
+
+    #[test]
+    fn ripple_carry_adder_matches_adder() {
+        let circuit = ripple_carry_adder(32);
+        for (a, b) in [
+            (10, 5),
+            (0, 15),
+            (3, 48),
+            (13, 0),
+            (u32::MAX, 1),
+            (0xDEAD_BEEF, 0xCAFE_BABE),
+        ] {
+            let report = circuit.simulate(a, b);
+            assert_eq!(report.sum, adder(a, b));
+            assert_eq!(report.carry_out, overflowing_adder(a, b).1);
+        }
+    }
+
+    #[test]
+    fn ripple_carry_adder_exhaustive_4_bits() {
+        let circuit = ripple_carry_adder(4);
+        for a in 0..16 {
+            for b in 0..16 {
+                let report = circuit.simulate(a, b);
+                assert_eq!(report.sum, adder(a, b) & 0xF);
+                assert_eq!(report.carry_out, adder(a, b) > 0xF);
+            }
+        }
+    }
+
+    #[test]
+    fn ripple_carry_adder_costs() {
+        let report = ripple_carry_adder(8).simulate(0xFF, 1);
+        // One half adder and seven full adders
+        assert_eq!(report.gate_count.total(), 2 + 7 * 5);
+        assert_eq!(report.critical_path_depth, 1 + 2 * 7);
+        assert!(report.carry_chain.iter().all(|bit| bit.carry_out));
+        assert_eq!(report.carry_chain[7].carry_out_depth, 15);
+        assert!(report.carry_out);
+    }
+}
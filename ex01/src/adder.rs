@@ -0,0 +1,8 @@
+pub fn adder(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        return a;
+    }
+    let carry = (a & b) << 1;
+
+    adder(a ^ b, carry)
+}
@@ -0,0 +1,467 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{
+    divider::restoring_divider,
+    error::{Error, Result},
+    iterative::{iterative_adder, iterative_multiplier},
+    unsigned::generic_overflowing_adder,
+};
+
+// Limb values only ever go through the bitwise adder, subtractor and
+// multiplier. Bit and limb positions, as in bit_length, bit, the shifts and the
+// quotient and product indexing, are usize bookkeeping and deliberately use
+// native arithmetic
+const LIMB_BITS: usize = u32::BITS as usize;
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+// Below this many limbs in the shorter operand the schoolbook product is cheaper
+const KARATSUBA_LIMBS: usize = 32;
+
+// Little-endian limbs, without trailing zero limbs so that zero is an empty vector
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+fn add_with_carry(a: u32, b: u32, carry: bool) -> (u32, bool) {
+    let (sum, first_carry) = generic_overflowing_adder(a, b);
+    let (sum, second_carry) = generic_overflowing_adder(sum, carry as u32);
+    (sum, first_carry || second_carry)
+}
+
+fn subtract_with_borrow(a: u32, b: u32, borrow: bool) -> (u32, bool) {
+    let (difference, carry) = add_with_carry(a, !b, !borrow);
+    (difference, !carry)
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0u128, |value, &limb| (value << LIMB_BITS) | limb as u128),
+        )
+    }
+
+    pub fn bit_length(&self) -> usize {
+        match self.limbs.last() {
+            None => 0,
+            Some(top_limb) => {
+                (self.limbs.len() - 1) * LIMB_BITS + (u32::BITS - top_limb.leading_zeros()) as usize
+            }
+        }
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.limbs
+            .get(index / LIMB_BITS)
+            .is_some_and(|limb| (limb >> (index % LIMB_BITS)) & 1 == 1)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let (longer, shorter) = if self.limbs.len() >= other.limbs.len() {
+            (&self.limbs, &other.limbs)
+        } else {
+            (&other.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(longer.len() + 1);
+        let mut carry = false;
+        for (index, &limb) in longer.iter().enumerate() {
+            let (sum, next_carry) =
+                add_with_carry(limb, shorter.get(index).copied().unwrap_or(0), carry);
+            limbs.push(sum);
+            carry = next_carry;
+        }
+        if carry {
+            limbs.push(1);
+        }
+        Self::from_limbs(limbs)
+    }
+
+    fn subtract_limbs(&self, other: &Self) -> (Vec<u32>, bool) {
+        let length = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(length);
+        let mut borrow = false;
+        for index in 0..length {
+            let (difference, next_borrow) = subtract_with_borrow(
+                self.limbs.get(index).copied().unwrap_or(0),
+                other.limbs.get(index).copied().unwrap_or(0),
+                borrow,
+            );
+            limbs.push(difference);
+            borrow = next_borrow;
+        }
+        (limbs, borrow)
+    }
+
+    pub fn subtract(&self, other: &Self) -> Result<Self> {
+        match self.subtract_limbs(other) {
+            (_, true) => Err(Error::SubtractionUnderflow),
+            (limbs, false) => Ok(Self::from_limbs(limbs)),
+        }
+    }
+
+    pub fn multiply(&self, other: &Self) -> Self {
+        if self.limbs.len().min(other.limbs.len()) < KARATSUBA_LIMBS {
+            return self.schoolbook_multiply(other);
+        }
+        self.karatsuba_multiply(other)
+    }
+
+    // With k = half the limbs: a * b = high * 2^2k + low
+    //     + ((a_low + a_high) * (b_low + b_high) - low - high) * 2^k
+    fn karatsuba_multiply(&self, other: &Self) -> Self {
+        let half = self.limbs.len().max(other.limbs.len()) / 2;
+        let (self_low, self_high) = self.split_at_limb(half);
+        let (other_low, other_high) = other.split_at_limb(half);
+        let low = self_low.multiply(&other_low);
+        let high = self_high.multiply(&other_high);
+        let middle = self_low
+            .add(&self_high)
+            .multiply(&other_low.add(&other_high))
+            .subtract(&low)
+            .and_then(|difference| difference.subtract(&high))
+            .expect("The product of the sums is never below the outer products");
+        high.shift_left(2 * half * LIMB_BITS)
+            .add(&middle.shift_left(half * LIMB_BITS))
+            .add(&low)
+    }
+
+    fn split_at_limb(&self, index: usize) -> (Self, Self) {
+        let (low, high) = self.limbs.split_at(index.min(self.limbs.len()));
+        (
+            Self::from_limbs(low.to_vec()),
+            Self::from_limbs(high.to_vec()),
+        )
+    }
+
+    fn schoolbook_multiply(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (self_index, &self_limb) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (other_index, &other_limb) in other.limbs.iter().enumerate() {
+                let product = iterative_multiplier(self_limb as u64, other_limb as u64);
+                let accumulated = &mut limbs[self_index + other_index];
+                let total = iterative_adder(iterative_adder(product, *accumulated as u64), carry);
+                *accumulated = total as u32;
+                carry = total >> LIMB_BITS;
+            }
+            limbs[self_index + other.limbs.len()] = carry as u32;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    pub fn divide(&self, divisor: &Self) -> Result<(Self, Self)> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        if let [single_limb] = divisor.limbs[..] {
+            let (quotient, remainder) = self.divide_by_limb(single_limb);
+            return Ok((quotient, Self::from(remainder)));
+        }
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = Self::zero();
+        for index in (0..self.bit_length()).rev() {
+            remainder = remainder.shift_left(1);
+            if self.bit(index) {
+                remainder = remainder.add(&Self::from(1u32));
+            }
+            if remainder >= *divisor {
+                remainder = remainder.subtract(divisor)?;
+                quotient[index / LIMB_BITS] |= 1 << (index % LIMB_BITS);
+            }
+        }
+        Ok((Self::from_limbs(quotient), remainder))
+    }
+
+    fn divide_by_limb(&self, divisor: u32) -> (Self, u32) {
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = 0u64;
+        for (index, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << LIMB_BITS) | limb as u64;
+            let (limb_quotient, limb_remainder) = restoring_divider(current, divisor as u64)
+                .expect("Divisor is known to be non zero at this point");
+            quotient[index] = limb_quotient as u32;
+            remainder = limb_remainder;
+        }
+        (Self::from_limbs(quotient), remainder as u32)
+    }
+
+    pub fn shift_left(&self, bits: usize) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let bit_shift = (bits % LIMB_BITS) as u32;
+        let mut limbs = vec![0u32; bits / LIMB_BITS];
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&self.limbs);
+        } else {
+            let mut carried_bits = 0u32;
+            for &limb in &self.limbs {
+                limbs.push((limb << bit_shift) | carried_bits);
+                carried_bits = limb >> (u32::BITS - bit_shift);
+            }
+            limbs.push(carried_bits);
+        }
+        Self::from_limbs(limbs)
+    }
+
+    pub fn shift_right(&self, bits: usize) -> Self {
+        let limb_shift = bits / LIMB_BITS;
+        let bit_shift = (bits % LIMB_BITS) as u32;
+        if limb_shift >= self.limbs.len() {
+            return Self::zero();
+        }
+        let kept_limbs = &self.limbs[limb_shift..];
+        if bit_shift == 0 {
+            return Self::from_limbs(kept_limbs.to_vec());
+        }
+        let limbs = kept_limbs
+            .iter()
+            .enumerate()
+            .map(|(index, &limb)| {
+                let next_limb = kept_limbs.get(index + 1).copied().unwrap_or(0);
+                (limb >> bit_shift) | (next_limb << (u32::BITS - bit_shift))
+            })
+            .collect();
+        Self::from_limbs(limbs)
+    }
+
+    pub fn from_hex(input: &str) -> Result<Self> {
+        let digits = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+            .unwrap_or(input);
+        Self::from_digits(digits, 16)
+    }
+
+    fn from_digits(digits: &str, radix: u32) -> Result<Self> {
+        if digits.is_empty() {
+            return Err(Error::InvalidNumberString);
+        }
+        let radix_value = Self::from(radix);
+        digits.chars().try_fold(Self::zero(), |value, character| {
+            let digit = character
+                .to_digit(radix)
+                .ok_or(Error::InvalidNumberString)?;
+            Ok(value.multiply(&radix_value).add(&Self::from(digit)))
+        })
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        Self::from_limbs(vec![value])
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        Self::from_limbs(
+            (0..4)
+                .map(|index| (value >> (index * LIMB_BITS)) as u32)
+                .collect(),
+        )
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.subtract_limbs(other) {
+            (_, true) => Ordering::Less,
+            (limbs, false) if limbs.iter().all(|&limb| limb == 0) => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::from_digits(input, 10)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chunks = vec![];
+        let mut value = self.clone();
+        while !value.is_zero() {
+            let (quotient, chunk) = value.divide_by_limb(DECIMAL_CHUNK);
+            chunks.push(chunk);
+            value = quotient;
+        }
+        let mut digits = match chunks.pop() {
+            Some(top_chunk) => top_chunk.to_string(),
+            None => "0".to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = match self.limbs.last() {
+            Some(top_limb) => format!("{top_limb:x}"),
+            None => "0".to_string(),
+        };
+        for limb in self.limbs.iter().rev().skip(1) {
+            digits.push_str(&format!("{limb:08x}"));
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWO_POW_200: &str = "1606938044258990275541962092341162602522202993782792835301376";
+
+    fn sample_values() -> Vec<u128> {
+        let mut value = 0x9E37_79B9_7F4A_7C15u64;
+        let mut values = vec![0, 1, u32::MAX as u128, u64::MAX as u128, u128::MAX];
+        for _ in 0..20 {
+            value ^= value << 13;
+            value ^= value >> 7;
+            value ^= value << 17;
+            values.push(value as u128);
+            values.push(((value as u128) << 40) | 0xABCD);
+        }
+        values
+    }
+
+    #[test]
+    fn big_uint_matches_u128() {
+        for a in sample_values() {
+            for b in sample_values() {
+                let big_a = BigUint::from(a);
+                let big_b = BigUint::from(b);
+                assert_eq!(big_a.cmp(&big_b), a.cmp(&b));
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(big_a.add(&big_b).to_u128(), Some(sum));
+                }
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(big_a.multiply(&big_b).to_u128(), Some(product));
+                }
+                match a.checked_sub(b) {
+                    Some(difference) => {
+                        assert_eq!(big_a.subtract(&big_b).unwrap().to_u128(), Some(difference))
+                    }
+                    None => assert!(matches!(
+                        big_a.subtract(&big_b),
+                        Err(Error::SubtractionUnderflow)
+                    )),
+                }
+                if let (Some(quotient), Some(remainder)) = (a.checked_div(b), a.checked_rem(b)) {
+                    let (big_quotient, big_remainder) = big_a.divide(&big_b).unwrap();
+                    assert_eq!(big_quotient.to_u128(), Some(quotient));
+                    assert_eq!(big_remainder.to_u128(), Some(remainder));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn big_uint_karatsuba_matches_schoolbook() {
+        let mut state = 0x243f_6a88_85a3_08d3u64;
+        let mut random = |length: usize| {
+            BigUint::from_limbs(
+                (0..length)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6_364_136_223_846_793_005)
+                            .wrapping_add(1_442_695_040_888_963_407);
+                        (state >> 32) as u32
+                    })
+                    .collect(),
+            )
+        };
+        for (a_length, b_length) in [(32, 32), (75, 40), (130, 129), (200, 33)] {
+            let a = random(a_length);
+            let b = random(b_length);
+            assert_eq!(a.multiply(&b), a.schoolbook_multiply(&b));
+            let (quotient, remainder) = a.multiply(&b).divide(&b).unwrap();
+            assert_eq!((quotient, remainder), (a, BigUint::zero()));
+        }
+    }
+
+    #[test]
+    fn big_uint_wide_values_ok() {
+        let two_pow_200 = BigUint::from(1u32).shift_left(200);
+        assert_eq!(two_pow_200.to_string(), TWO_POW_200);
+        assert_eq!(two_pow_200.bit_length(), 201);
+        assert_eq!(TWO_POW_200.parse::<BigUint>().unwrap(), two_pow_200);
+        assert_eq!(two_pow_200.shift_right(199).to_u128(), Some(2));
+
+        let factor = BigUint::from(u128::MAX);
+        let product = factor.multiply(&factor).multiply(&factor);
+        let (quotient, remainder) = product.add(&BigUint::from(42u32)).divide(&factor).unwrap();
+        assert_eq!(quotient, factor.multiply(&factor));
+        assert_eq!(remainder.to_u128(), Some(42));
+        assert!(product > two_pow_200);
+    }
+
+    #[test]
+    fn big_uint_hex_ok() {
+        let value = BigUint::from_hex("0x1DEADBEEF00000000CAFEBABE").unwrap();
+        assert_eq!(format!("{value:x}"), "1deadbeef00000000cafebabe");
+        assert_eq!(format!("{value:#x}"), "0x1deadbeef00000000cafebabe");
+        assert_eq!(value.to_u128(), Some(0x0001_DEAD_BEEF_0000_0000_CAFE_BABE));
+        assert_eq!(format!("{:x}", BigUint::zero()), "0");
+        assert_eq!(BigUint::zero().to_string(), "0");
+    }
+
+    #[test]
+    fn big_uint_err_invalid_number_string() {
+        assert!(matches!(
+            "12a4".parse::<BigUint>(),
+            Err(Error::InvalidNumberString)
+        ));
+        assert!(matches!(
+            BigUint::from_hex("0x"),
+            Err(Error::InvalidNumberString)
+        ));
+        assert!(matches!(
+            BigUint::from(1u32).divide(&BigUint::zero()),
+            Err(Error::DivisionByZero)
+        ));
+    }
+}
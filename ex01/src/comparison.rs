@@ -0,0 +1,107 @@
+use crate::{
+    signed::Signed,
+    unsigned::{generic_adder, generic_negate, generic_subtractor, Unsigned},
+};
+
+// All ones when the top bit of `value` is set, zero otherwise
+fn smear_highest_bit<T: Unsigned>(value: T) -> T {
+    generic_negate((value & T::HIGHEST_BIT) >> generic_subtractor(T::BITS, 1))
+}
+
+// The borrow out of a - b lands in the top bit of this expression
+fn less_than_mask<T: Unsigned>(a: T, b: T) -> T {
+    let difference = generic_subtractor(a, b);
+    smear_highest_bit((!a & b) | (!(a ^ b) & difference))
+}
+
+pub fn equal<T: Unsigned>(a: T, b: T) -> bool {
+    a ^ b == T::ZERO
+}
+
+pub fn less_than<T: Unsigned>(a: T, b: T) -> bool {
+    less_than_mask(a, b) != T::ZERO
+}
+
+pub fn less_or_equal<T: Unsigned>(a: T, b: T) -> bool {
+    !less_than(b, a)
+}
+
+pub fn greater_than<T: Unsigned>(a: T, b: T) -> bool {
+    less_than(b, a)
+}
+
+pub fn greater_or_equal<T: Unsigned>(a: T, b: T) -> bool {
+    !less_than(a, b)
+}
+
+pub fn branchless_min<T: Unsigned>(a: T, b: T) -> T {
+    b ^ ((a ^ b) & less_than_mask(a, b))
+}
+
+pub fn branchless_max<T: Unsigned>(a: T, b: T) -> T {
+    a ^ ((a ^ b) & less_than_mask(a, b))
+}
+
+// Flipping the sign bit maps two's complement order onto unsigned order
+fn biased<T: Signed>(value: T) -> T::Bits {
+    value.to_bits() ^ T::Bits::HIGHEST_BIT
+}
+
+pub fn signed_less_than<T: Signed>(a: T, b: T) -> bool {
+    less_than(biased(a), biased(b))
+}
+
+pub fn signed_min<T: Signed>(a: T, b: T) -> T {
+    let mask = less_than_mask(biased(a), biased(b));
+    T::from_bits(b.to_bits() ^ ((a.to_bits() ^ b.to_bits()) & mask))
+}
+
+pub fn signed_max<T: Signed>(a: T, b: T) -> T {
+    let mask = less_than_mask(biased(a), biased(b));
+    T::from_bits(a.to_bits() ^ ((a.to_bits() ^ b.to_bits()) & mask))
+}
+
+// Wraps on the minimum value, like i32::wrapping_abs
+pub fn branchless_abs<T: Signed>(value: T) -> T {
+    let mask = smear_highest_bit(value.to_bits());
+    T::from_bits(generic_adder(value.to_bits() ^ mask, mask & T::Bits::ONE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparison_test_ok() {
+        assert!(less_than(3u32, 48));
+        assert!(!less_than(48u32, 3));
+        assert!(!less_than(13u32, 13));
+        assert!(less_or_equal(13u32, 13));
+        assert!(greater_than(u64::MAX, u64::MAX >> 1));
+        assert!(greater_or_equal(0u8, 0));
+        assert!(equal(u128::MAX, u128::MAX));
+        assert!(signed_less_than(-1i32, 0));
+        assert!(signed_less_than(i64::MIN, i64::MAX));
+        assert_eq!(branchless_abs(i32::MIN), i32::MIN);
+        assert_eq!(branchless_abs(-42i64), 42);
+    }
+
+    #[test]
+    fn comparison_exhaustive_8_bits() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(less_than(a, b), a < b);
+                assert_eq!(less_or_equal(a, b), a <= b);
+                assert_eq!(equal(a, b), a == b);
+                assert_eq!(branchless_min(a, b), a.min(b));
+                assert_eq!(branchless_max(a, b), a.max(b));
+
+                let (a, b) = (a as i8, b as i8);
+                assert_eq!(signed_less_than(a, b), a < b);
+                assert_eq!(signed_min(a, b), a.min(b));
+                assert_eq!(signed_max(a, b), a.max(b));
+            }
+            assert_eq!(branchless_abs(a as i8), (a as i8).wrapping_abs());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    error::{Error, Result},
+    unsigned::{generic_adder, generic_negate, generic_overflowing_adder, Unsigned},
+};
+
+// Partial remainder of the non-restoring algorithm, which needs two extra bits
+// over the operand width, stored as a two's complement double word
+#[derive(Clone, Copy)]
+struct DoubleWord<T> {
+    high: T,
+    low: T,
+}
+
+impl<T: Unsigned> DoubleWord<T> {
+    fn from_low(low: T) -> Self {
+        Self { high: T::ZERO, low }
+    }
+
+    fn is_negative(&self) -> bool {
+        self.high & T::HIGHEST_BIT != T::ZERO
+    }
+
+    fn shift_in(self, bit: T) -> Self {
+        let carried_bit = if self.low & T::HIGHEST_BIT != T::ZERO {
+            T::ONE
+        } else {
+            T::ZERO
+        };
+        Self {
+            high: (self.high << 1) | carried_bit,
+            low: (self.low << 1) | bit,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let (low, carry) = generic_overflowing_adder(self.low, other.low);
+        let carry = if carry { T::ONE } else { T::ZERO };
+        Self {
+            high: generic_adder(generic_adder(self.high, other.high), carry),
+            low,
+        }
+    }
+
+    fn negate(self) -> Self {
+        Self {
+            high: !self.high,
+            low: !self.low,
+        }
+        .add(Self::from_low(T::ONE))
+    }
+}
+
+fn bit_at<T: Unsigned>(value: T, index: u32) -> T {
+    (value >> index) & T::ONE
+}
+
+pub fn restoring_divider<T: Unsigned>(dividend: T, divisor: T) -> Result<(T, T)> {
+    if divisor == T::ZERO {
+        return Err(Error::DivisionByZero);
+    }
+    let negated_divisor = generic_negate(divisor);
+    let mut quotient = T::ZERO;
+    let mut remainder = T::ZERO;
+
+    for index in (0..T::BITS).rev() {
+        // A bit shifted out of the remainder means it exceeds any divisor
+        let shifted_out = remainder & T::HIGHEST_BIT != T::ZERO;
+        remainder = (remainder << 1) | bit_at(dividend, index);
+
+        let (difference, no_borrow) = generic_overflowing_adder(remainder, negated_divisor);
+        if shifted_out || no_borrow {
+            remainder = difference;
+            quotient = quotient | (T::ONE << index);
+        } else {
+            // The trial subtraction went negative, add the divisor back
+            remainder = generic_adder(difference, divisor);
+        }
+    }
+    Ok((quotient, remainder))
+}
+
+pub fn non_restoring_divider<T: Unsigned>(dividend: T, divisor: T) -> Result<(T, T)> {
+    if divisor == T::ZERO {
+        return Err(Error::DivisionByZero);
+    }
+    let divisor = DoubleWord::from_low(divisor);
+    let negated_divisor = divisor.negate();
+    let mut quotient = T::ZERO;
+    let mut remainder = DoubleWord::from_low(T::ZERO);
+
+    for index in (0..T::BITS).rev() {
+        let was_negative = remainder.is_negative();
+        remainder = remainder.shift_in(bit_at(dividend, index));
+        remainder = if was_negative {
+            remainder.add(divisor)
+        } else {
+            remainder.add(negated_divisor)
+        };
+        if !remainder.is_negative() {
+            quotient = quotient | (T::ONE << index);
+        }
+    }
+    if remainder.is_negative() {
+        remainder = remainder.add(divisor);
+    }
+    Ok((quotient, remainder.low))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divider_test_ok() {
+        assert_eq!(restoring_divider(50u32, 5).unwrap(), (10, 0));
+        assert_eq!(restoring_divider(51u32, 5).unwrap(), (10, 1));
+        assert_eq!(restoring_divider(3u32, 48).unwrap(), (0, 3));
+        assert_eq!(non_restoring_divider(50u32, 5).unwrap(), (10, 0));
+        assert_eq!(non_restoring_divider(51u32, 5).unwrap(), (10, 1));
+        assert_eq!(non_restoring_divider(3u32, 48).unwrap(), (0, 3));
+        assert_eq!(
+            restoring_divider(u128::MAX, u128::MAX >> 1).unwrap(),
+            (2, 1)
+        );
+        assert_eq!(
+            non_restoring_divider(u64::MAX, 0x8000_0000_0000_0001).unwrap(),
+            (1, 0x7FFF_FFFF_FFFF_FFFE)
+        );
+    }
+
+    #[test]
+    fn divider_err_division_by_zero() {
+        assert!(matches!(
+            restoring_divider(13u32, 0),
+            Err(Error::DivisionByZero)
+        ));
+        assert!(matches!(
+            non_restoring_divider(13u32, 0),
+            Err(Error::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn divider_exhaustive_u8() {
+        for dividend in 0..=u8::MAX {
+            for divisor in 1..=u8::MAX {
+                let expected = (dividend / divisor, dividend % divisor);
+                assert_eq!(restoring_divider(dividend, divisor).unwrap(), expected);
+                assert_eq!(non_restoring_divider(dividend, divisor).unwrap(), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    DivisionByZero,
+    SubtractionUnderflow,
+    InvalidNumberString,
+    InvalidPolynomialDegree,
+    ReduciblePolynomial,
+    NoMultiplicativeInverse,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DivisionByZero => write!(f, "Attempted to divide by zero"),
+            Error::SubtractionUnderflow => write!(f, "Subtraction result would be negative"),
+            Error::InvalidNumberString => write!(f, "Number string contains invalid digits"),
+            Error::InvalidPolynomialDegree => {
+                write!(f, "Field polynomial degree should be between 1 and 64")
+            }
+            Error::ReduciblePolynomial => write!(f, "Field polynomial is not irreducible"),
+            Error::NoMultiplicativeInverse => write!(f, "Zero has no multiplicative inverse"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,317 @@
+use crate::{
+    iterative::{iterative_adder, iterative_multiplier},
+    signed::{signed_adder, signed_subtractor},
+    unsigned::generic_subtractor,
+};
+
+const FRACTION_BITS: u32 = 23;
+const FRACTION_MASK: u32 = (1 << FRACTION_BITS) - 1;
+const IMPLICIT_BIT: u64 = 1 << FRACTION_BITS;
+const EXPONENT_MASK: u32 = 0xFF;
+const EXPONENT_BIAS: i32 = 127;
+const MAX_EXPONENT: i32 = 0xFF;
+const SIGN_BIT: u32 = 1 << 31;
+const QUIET_BIT: u32 = 1 << (FRACTION_BITS - 1);
+const DEFAULT_NAN: u32 = 0x7FC0_0000;
+// Guard, round and sticky bits kept below the significand during addition
+const ADDITION_EXTRA_BITS: u32 = 3;
+
+#[derive(Debug, Clone, Copy)]
+struct Unpacked {
+    sign: bool,
+    exponent: u32,
+    fraction: u32,
+}
+
+impl Unpacked {
+    fn new(value: f32) -> Self {
+        let bits = value.to_bits();
+        Self {
+            sign: bits & SIGN_BIT != 0,
+            exponent: (bits >> FRACTION_BITS) & EXPONENT_MASK,
+            fraction: bits & FRACTION_MASK,
+        }
+    }
+
+    fn is_nan(&self) -> bool {
+        self.exponent == EXPONENT_MASK && self.fraction != 0
+    }
+
+    fn is_infinite(&self) -> bool {
+        self.exponent == EXPONENT_MASK && self.fraction == 0
+    }
+
+    fn is_zero(&self) -> bool {
+        self.exponent == 0 && self.fraction == 0
+    }
+
+    // Subnormals share the exponent of the smallest normal numbers, without the implicit bit
+    fn significand(&self) -> u64 {
+        match self.exponent {
+            0 => self.fraction as u64,
+            _ => IMPLICIT_BIT | self.fraction as u64,
+        }
+    }
+
+    fn effective_exponent(&self) -> i32 {
+        self.exponent.max(1) as i32
+    }
+}
+
+fn pack(sign: bool, exponent: u32, fraction: u32) -> f32 {
+    let sign = if sign { SIGN_BIT } else { 0 };
+    f32::from_bits(sign | (exponent << FRACTION_BITS) | (fraction & FRACTION_MASK))
+}
+
+fn infinity(sign: bool) -> f32 {
+    pack(sign, EXPONENT_MASK, 0)
+}
+
+fn zero(sign: bool) -> f32 {
+    pack(sign, 0, 0)
+}
+
+fn quiet(value: f32) -> f32 {
+    f32::from_bits(value.to_bits() | QUIET_BIT)
+}
+
+fn low_bits_mask(bits: u32) -> u64 {
+    !(u64::MAX << bits)
+}
+
+// Shifts right while or-ing every bit shifted out into the lowest bit
+fn shift_right_sticky(value: u64, shift: u32) -> u64 {
+    if shift >= u64::BITS {
+        return (value != 0) as u64;
+    }
+    let sticky = (value & low_bits_mask(shift) != 0) as u64;
+    (value >> shift) | sticky
+}
+
+// `significand` has its leading bit at FRACTION_BITS + extra_bits for normal
+// results, `exponent` being the biased exponent matching that position
+fn round_and_pack(sign: bool, exponent: i32, significand: u64, extra_bits: u32) -> f32 {
+    let (mut exponent, mut significand) = (exponent, significand);
+    if exponent < 1 {
+        significand = shift_right_sticky(significand, signed_subtractor(1, exponent) as u32);
+        exponent = 1;
+    }
+    let halfway = 1 << (extra_bits - 1);
+    let remainder = significand & low_bits_mask(extra_bits);
+    significand >>= extra_bits;
+    if remainder > halfway || (remainder == halfway && significand & 1 == 1) {
+        significand = iterative_adder(significand, 1);
+    }
+    if significand == IMPLICIT_BIT << 1 {
+        significand >>= 1;
+        exponent = signed_adder(exponent, 1);
+    }
+    if exponent >= MAX_EXPONENT {
+        return infinity(sign);
+    }
+    let exponent_field = if significand & IMPLICIT_BIT == 0 {
+        0
+    } else {
+        exponent as u32
+    };
+    pack(sign, exponent_field, significand as u32)
+}
+
+pub fn float_adder(a: f32, b: f32) -> f32 {
+    let (unpacked_a, unpacked_b) = (Unpacked::new(a), Unpacked::new(b));
+    if unpacked_a.is_nan() {
+        return quiet(a);
+    }
+    if unpacked_b.is_nan() {
+        return quiet(b);
+    }
+    match (unpacked_a.is_infinite(), unpacked_b.is_infinite()) {
+        (true, true) if unpacked_a.sign != unpacked_b.sign => return f32::from_bits(DEFAULT_NAN),
+        (true, _) => return a,
+        (_, true) => return b,
+        _ => {}
+    }
+    if unpacked_a.is_zero() && unpacked_b.is_zero() {
+        return zero(unpacked_a.sign && unpacked_b.sign);
+    }
+
+    // Order by magnitude, the sign-less bit patterns of floats sort like their values
+    let (larger, smaller) = if a.to_bits() & !SIGN_BIT >= b.to_bits() & !SIGN_BIT {
+        (unpacked_a, unpacked_b)
+    } else {
+        (unpacked_b, unpacked_a)
+    };
+    let mut exponent = larger.effective_exponent();
+    let exponent_difference = signed_subtractor(exponent, smaller.effective_exponent()) as u32;
+    let larger_significand = larger.significand() << ADDITION_EXTRA_BITS;
+    let smaller_significand = shift_right_sticky(
+        smaller.significand() << ADDITION_EXTRA_BITS,
+        exponent_difference,
+    );
+    let leading_bit = IMPLICIT_BIT << ADDITION_EXTRA_BITS;
+
+    let mut significand;
+    if larger.sign == smaller.sign {
+        significand = iterative_adder(larger_significand, smaller_significand);
+        if significand >= leading_bit << 1 {
+            significand = shift_right_sticky(significand, 1);
+            exponent = signed_adder(exponent, 1);
+        }
+    } else {
+        significand = generic_subtractor(larger_significand, smaller_significand);
+        if significand == 0 {
+            return zero(false);
+        }
+        while significand < leading_bit && exponent > 1 {
+            significand <<= 1;
+            exponent = signed_subtractor(exponent, 1);
+        }
+    }
+    round_and_pack(larger.sign, exponent, significand, ADDITION_EXTRA_BITS)
+}
+
+pub fn float_multiplier(a: f32, b: f32) -> f32 {
+    let (unpacked_a, unpacked_b) = (Unpacked::new(a), Unpacked::new(b));
+    if unpacked_a.is_nan() {
+        return quiet(a);
+    }
+    if unpacked_b.is_nan() {
+        return quiet(b);
+    }
+    let sign = unpacked_a.sign ^ unpacked_b.sign;
+    match (unpacked_a, unpacked_b) {
+        (infinite, zero) | (zero, infinite) if infinite.is_infinite() && zero.is_zero() => {
+            return f32::from_bits(DEFAULT_NAN)
+        }
+        (first, second) if first.is_infinite() || second.is_infinite() => return infinity(sign),
+        (first, second) if first.is_zero() || second.is_zero() => return zero(sign),
+        _ => {}
+    }
+
+    // The product of two significands has its leading bit at 2 * FRACTION_BITS
+    // or one above, the bits below FRACTION_BITS of that are rounded away
+    let product_leading_bit = FRACTION_BITS << 1;
+    let mut significand = iterative_multiplier(unpacked_a.significand(), unpacked_b.significand());
+    let mut exponent = signed_subtractor(
+        signed_adder(
+            unpacked_a.effective_exponent(),
+            unpacked_b.effective_exponent(),
+        ),
+        EXPONENT_BIAS,
+    );
+    let leading_bit = u64::BITS - 1 - significand.leading_zeros();
+    if leading_bit > product_leading_bit {
+        significand = shift_right_sticky(significand, 1);
+        exponent = signed_adder(exponent, 1);
+    } else {
+        let shift = product_leading_bit - leading_bit;
+        significand <<= shift;
+        exponent = signed_subtractor(exponent, shift as i32);
+    }
+    round_and_pack(sign, exponent, significand, FRACTION_BITS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_same(result: f32, expected: f32, a: f32, b: f32) {
+        if expected.is_nan() {
+            assert!(result.is_nan(), "{a:e} {b:e}: {result:e} is not NaN");
+        } else {
+            assert_eq!(
+                result.to_bits(),
+                expected.to_bits(),
+                "{a:e} {b:e}: {result:e} != {expected:e}"
+            );
+        }
+    }
+
+    fn random_floats(count: usize) -> Vec<f32> {
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            values.push(f32::from_bits(state as u32));
+        }
+        values
+    }
+
+    const SPECIAL_VALUES: [f32; 14] = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::MIN_POSITIVE,
+        -f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::MIN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        1.0e-45,
+        -1.0e-45,
+        1.1754942e-38,
+    ];
+
+    #[test]
+    fn float_adder_test_ok() {
+        assert_eq!(float_adder(1.5, 2.25), 3.75);
+        assert_eq!(float_adder(0.1, 0.2), 0.1 + 0.2);
+        assert_eq!(float_adder(1.0, -1.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(float_adder(f32::MAX, f32::MAX), f32::INFINITY);
+        assert_eq!(float_adder(1.0e-45, 1.0e-45), f32::from_bits(2));
+        assert!(float_adder(f32::INFINITY, f32::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn float_multiplier_test_ok() {
+        assert_eq!(float_multiplier(1.5, 2.25), 3.375);
+        assert_eq!(float_multiplier(0.1, 0.2), 0.1 * 0.2);
+        assert_eq!(float_multiplier(-0.0, 5.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(float_multiplier(f32::MAX, 2.0), f32::INFINITY);
+        assert_eq!(
+            float_multiplier(f32::MIN_POSITIVE, 0.5),
+            f32::MIN_POSITIVE / 2.0
+        );
+        assert!(float_multiplier(f32::INFINITY, 0.0).is_nan());
+    }
+
+    #[test]
+    fn float_special_values_match_native() {
+        for a in SPECIAL_VALUES {
+            for b in SPECIAL_VALUES {
+                assert_same(float_adder(a, b), a + b, a, b);
+                assert_same(float_multiplier(a, b), a * b, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn float_random_bits_match_native() {
+        let values = random_floats(20_000);
+        for pair in values.chunks(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_same(float_adder(a, b), a + b, a, b);
+            assert_same(float_multiplier(a, b), a * b, a, b);
+        }
+    }
+
+    #[test]
+    fn float_random_close_values_match_native() {
+        // Nearby exponents exercise cancellation, and small exponents the subnormal range
+        let values = random_floats(20_000);
+        for pair in values.chunks(2) {
+            for exponent_mask in [0x3F80_0000, 0x0080_0000, 0x0000_0000] {
+                let a = f32::from_bits((pair[0].to_bits() & 0x807F_FFFF) | exponent_mask);
+                let b = f32::from_bits((pair[1].to_bits() & 0x80FF_FFFF) | exponent_mask);
+                assert_same(float_adder(a, b), a + b, a, b);
+                assert_same(float_multiplier(a, b), a * b, a, b);
+                let tiny = f32::from_bits(pair[1].to_bits() & 0x81FF_FFFF);
+                assert_same(float_multiplier(a, tiny), a * tiny, a, tiny);
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    error::{Error, Result},
+    unsigned::Unsigned,
+};
+
+const AES_POLYNOMIAL: u128 = 0x11B;
+const MAX_DEGREE: u32 = u64::BITS;
+
+// Shift-and-add multiplication where xor replaces the adder, so no carry ever
+// propagates between bits
+pub fn carry_less_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        return T::ZERO;
+    }
+    let partial_product = if b & T::ONE == T::ONE { a } else { T::ZERO };
+
+    partial_product ^ carry_less_multiplier(a << 1, b >> 1)
+}
+
+fn degree(polynomial: u128) -> Option<u32> {
+    match polynomial {
+        0 => None,
+        _ => Some(u128::BITS - 1 - polynomial.leading_zeros()),
+    }
+}
+
+pub fn polynomial_remainder(dividend: u128, divisor: u128) -> Result<u128> {
+    let Some(divisor_degree) = degree(divisor) else {
+        return Err(Error::DivisionByZero);
+    };
+    let mut remainder = dividend;
+    while let Some(remainder_degree) = degree(remainder).filter(|&d| d >= divisor_degree) {
+        remainder ^= divisor << (remainder_degree - divisor_degree);
+    }
+    Ok(remainder)
+}
+
+fn polynomial_gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        return a;
+    }
+    let remainder = polynomial_remainder(a, b).expect("b was checked to be non-zero");
+    polynomial_gcd(b, remainder)
+}
+
+fn prime_factors(value: u32) -> Vec<u32> {
+    let mut factors = vec![];
+    let mut remaining = value;
+    let mut candidate = 2;
+    while remaining > 1 {
+        if remaining.is_multiple_of(candidate) {
+            factors.push(candidate);
+            while remaining.is_multiple_of(candidate) {
+                remaining /= candidate;
+            }
+        }
+        candidate += 1;
+    }
+    factors
+}
+
+// GF(2^n), elements being polynomials over GF(2) of degree below n stored as bits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaloisField {
+    polynomial: u128,
+    degree: u32,
+}
+
+impl GaloisField {
+    pub fn new(polynomial: u128) -> Result<Self> {
+        let degree = match degree(polynomial) {
+            Some(degree) if (1..=MAX_DEGREE).contains(&degree) => degree,
+            _ => return Err(Error::InvalidPolynomialDegree),
+        };
+        let field = Self { polynomial, degree };
+        if !field.is_irreducible() {
+            return Err(Error::ReduciblePolynomial);
+        }
+        Ok(field)
+    }
+
+    pub fn aes() -> Self {
+        Self::new(AES_POLYNOMIAL).expect("The AES polynomial is irreducible")
+    }
+
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    // Rabin's test: x^(2^n) = x mod f, and x^(2^(n/q)) - x shares no factor
+    // with f for every prime q dividing n
+    fn is_irreducible(&self) -> bool {
+        let x = self.reduce(0b10) as u128;
+        let frobenius = |iterations: u32| {
+            (0..iterations).fold(x as u64, |value, _| self.multiply(value, value)) as u128
+        };
+        if frobenius(self.degree) != x {
+            return false;
+        }
+        prime_factors(self.degree).into_iter().all(|factor| {
+            let difference = frobenius(self.degree / factor) ^ x;
+            polynomial_gcd(self.polynomial, difference) == 1
+        })
+    }
+
+    pub fn reduce(&self, value: u128) -> u64 {
+        polynomial_remainder(value, self.polynomial).expect("A field polynomial is never zero")
+            as u64
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        self.reduce((a ^ b) as u128)
+    }
+
+    pub fn multiply(&self, a: u64, b: u64) -> u64 {
+        self.reduce(carry_less_multiplier(a as u128, b as u128))
+    }
+
+    pub fn power(&self, base: u64, exponent: u64) -> u64 {
+        let mut result = 1;
+        let mut square = self.reduce(base as u128);
+        let mut remaining_exponent = exponent;
+        while remaining_exponent != 0 {
+            if remaining_exponent & 1 == 1 {
+                result = self.multiply(result, square);
+            }
+            square = self.multiply(square, square);
+            remaining_exponent >>= 1;
+        }
+        self.reduce(result as u128)
+    }
+
+    // The multiplicative group has 2^n - 1 elements, so a^(2^n - 2) is a^-1
+    pub fn inverse(&self, a: u64) -> Result<u64> {
+        if self.reduce(a as u128) == 0 {
+            return Err(Error::NoMultiplicativeInverse);
+        }
+        let group_order = u64::MAX >> (MAX_DEGREE - self.degree);
+        Ok(self.power(a, group_order ^ 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carry_less_multiplier_test_ok() {
+        assert_eq!(carry_less_multiplier(0b11u8, 0b11), 0b101);
+        assert_eq!(carry_less_multiplier(0x57u32, 0x83), 0x2B79);
+        assert_eq!(carry_less_multiplier(13u64, 0), 0);
+    }
+
+    #[test]
+    fn aes_field_test_ok() {
+        let field = GaloisField::aes();
+        assert_eq!(field.degree(), 8);
+        assert_eq!(field.add(0x57, 0x83), 0xD4);
+        assert_eq!(field.multiply(0x57, 0x83), 0xC1);
+        assert_eq!(field.multiply(0x57, 0x13), 0xFE);
+        assert_eq!(field.inverse(0x53).unwrap(), 0xCA);
+        assert_eq!(field.power(0x03, 255), 1);
+        for element in 1..=0xFF {
+            let inverse = field.inverse(element).unwrap();
+            assert_eq!(field.multiply(element, inverse), 1);
+        }
+    }
+
+    #[test]
+    fn wide_field_test_ok() {
+        let field = GaloisField::new((1 << 64) | 0x1B).unwrap();
+        let element = 0xDEAD_BEEF_CAFE_BABE;
+        let inverse = field.inverse(element).unwrap();
+        assert_eq!(field.multiply(element, inverse), 1);
+        assert_eq!(polynomial_remainder(0b1101_0000, 0b1011).unwrap(), 0b10);
+        assert!(matches!(
+            polynomial_remainder(0b1101, 0),
+            Err(Error::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn galois_field_err_invalid_polynomial() {
+        assert!(matches!(
+            GaloisField::new(0x11A),
+            Err(Error::ReduciblePolynomial)
+        ));
+        assert!(matches!(
+            GaloisField::new(0b101),
+            Err(Error::ReduciblePolynomial)
+        ));
+        assert!(matches!(
+            GaloisField::new(1),
+            Err(Error::InvalidPolynomialDegree)
+        ));
+        assert!(matches!(
+            GaloisField::new(1 << 65),
+            Err(Error::InvalidPolynomialDegree)
+        ));
+        assert!(matches!(
+            GaloisField::aes().inverse(0),
+            Err(Error::NoMultiplicativeInverse)
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::unsigned::Unsigned;
+
+pub fn iterative_adder<T: Unsigned>(a: T, b: T) -> T {
+    let mut sum = a;
+    let mut carry = b;
+    while carry != T::ZERO {
+        let next_carry = (sum & carry) << 1;
+        sum = sum ^ carry;
+        carry = next_carry;
+    }
+    sum
+}
+
+pub fn iterative_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    let mut product = T::ZERO;
+    let mut shifted_a = a;
+    let mut remaining_b = b;
+    while remaining_b != T::ZERO {
+        if remaining_b & T::ONE == T::ONE {
+            product = iterative_adder(product, shifted_a);
+        }
+        shifted_a = shifted_a << 1;
+        remaining_b = remaining_b >> 1;
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned::{generic_adder, generic_multiplier};
+
+    #[test]
+    fn iterative_test_ok() {
+        assert_eq!(iterative_adder(10u32, 5), 15);
+        assert_eq!(iterative_adder(u128::MAX, 1), 0);
+        assert_eq!(iterative_multiplier(3u32, 48), 144);
+        assert_eq!(iterative_multiplier(13u64, 0), 0);
+        assert_eq!(
+            iterative_multiplier(u128::MAX, u128::MAX),
+            u128::MAX.wrapping_mul(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn iterative_matches_recursive_exhaustive_u8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(iterative_adder(a, b), generic_adder(a, b));
+                assert_eq!(iterative_multiplier(a, b), generic_multiplier(a, b));
+            }
+        }
+    }
+
+    // Every a against a stride of b, then every b against a stride of a, so
+    // each u16 value is exercised as both operands
+    #[test]
+    fn iterative_matches_recursive_exhaustive_operands_u16() {
+        let strided = || {
+            (0..=u16::MAX)
+                .step_by(2053)
+                .chain([1, u16::MAX - 1, u16::MAX])
+        };
+        let pairs = (0..=u16::MAX)
+            .flat_map(|a| strided().map(move |b| (a, b)))
+            .chain((0..=u16::MAX).flat_map(|b| strided().map(move |a| (a, b))));
+        for (a, b) in pairs {
+            assert_eq!(iterative_adder(a, b), generic_adder(a, b));
+            assert_eq!(iterative_multiplier(a, b), generic_multiplier(a, b));
+        }
+    }
+
+    // Every u16 pair, too slow for the default run: cargo test --release -- --ignored
+    #[test]
+    #[ignore]
+    fn iterative_matches_recursive_exhaustive_u16() {
+        for a in 0..=u16::MAX {
+            for b in 0..=u16::MAX {
+                assert_eq!(iterative_adder(a, b), generic_adder(a, b));
+                assert_eq!(iterative_multiplier(a, b), generic_multiplier(a, b));
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod adder;
+pub mod big_uint;
+pub mod comparison;
+pub mod divider;
+pub mod error;
+pub mod float;
+pub mod galois_field;
+pub mod iterative;
+pub mod multiplication;
+pub mod multiplier;
+pub mod number_theory;
+pub mod signed;
+pub mod square_root;
+pub mod subtractor;
+pub mod trace;
+pub mod unsigned;
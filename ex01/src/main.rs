@@ -1,38 +1,72 @@
+use ex01::{
+    big_uint::BigUint,
+    comparison::{branchless_abs, branchless_min, signed_less_than},
+    divider::{non_restoring_divider, restoring_divider},
+    float::{float_adder, float_multiplier},
+    galois_field::GaloisField,
+    iterative::{iterative_adder, iterative_multiplier},
+    multiplication::{multiplier_with, signed_multiplier_with, MultiplicationAlgorithm},
+    multiplier::multiplier,
+    number_theory::{binary_gcd, extended_binary_gcd, modular_exponentiation},
+    signed::{signed_adder, signed_multiplier},
+    square_root::integer_square_root,
+    subtractor::subtractor,
+    trace::{trace_adder, trace_multiplier},
+    unsigned::{generic_adder, generic_multiplier},
+};
+
 fn main() {
     println!("10 * 5 : {}", multiplier(10, 5));
     println!("0 * 15 : {}", multiplier(0, 15));
     println!("3 * 48 : {}", multiplier(3, 48));
     println!("13 * 0 : {}", multiplier(13, 0));
-}
-
-fn multiplier(a: u32, b: u32) -> u32 {
-    let mut res = 0;
-    if b == 0 {
-        return 0;
-    }
-    if b & 1 == 1 {
-        res = adder(res, a);
-    }
-    return res + multiplier(a << 1, b >> 1);
-}
-
-fn adder(a: u32, b: u32) -> u32 {
-    if b == 0 {
-        return a;
+    println!("u8 200 + 100 : {}", generic_adder(200u8, 100));
+    println!("48 - 3 : {}", subtractor(48, 3));
+    println!("i32 -10 + 5 : {}", signed_adder(-10i32, 5));
+    println!("i64 -10 * 5 : {}", signed_multiplier(-10i64, 5));
+    println!("u64 2^40 * 3 : {}", generic_multiplier(1u64 << 40, 3));
+    println!("iterative u128 max + 1 : {}", iterative_adder(u128::MAX, 1));
+    println!(
+        "iterative u16 300 * 7 : {}",
+        iterative_multiplier(300u16, 7)
+    );
+    println!(
+        "booth radix-4 -7 * 6 : {}",
+        signed_multiplier_with(-7i32, 6, MultiplicationAlgorithm::BoothRadix4)
+    );
+    println!(
+        "karatsuba u128 2^70 * 3 : {}",
+        multiplier_with(1u128 << 70, 3, MultiplicationAlgorithm::Karatsuba)
+    );
+    println!("51 / 5 : {:?}", restoring_divider(51u32, 5));
+    println!("51 / 5 : {:?}", non_restoring_divider(51u32, 5));
+    match restoring_divider(13u32, 0) {
+        Ok(result) => println!("13 / 0 : {result:?}"),
+        Err(e) => println!("13 / 0 : {e}"),
     }
-    let carry = (a & b) << 1;
-
-    adder(a ^ b, carry)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn multiplier_test_ok() {
-        assert_eq!(multiplier(10, 5), 50);
-        assert_eq!(multiplier(0, 15), 0);
-        assert_eq!(multiplier(3, 48), 144);
-        assert_eq!(multiplier(13, 0), 0);
+    let factor = BigUint::from(u128::MAX);
+    println!("(2^128 - 1)^2 : {}", factor.multiply(&factor));
+    println!("{}", trace_adder(10u8, 5));
+    println!("{}", trace_multiplier(3u8, 48));
+    let aes_field = GaloisField::aes();
+    println!(
+        "GF(2^8) 0x57 * 0x83 : {:#x}",
+        aes_field.multiply(0x57, 0x83)
+    );
+    match aes_field.inverse(0x53) {
+        Ok(inverse) => println!("GF(2^8) 0x53^-1 : {inverse:#x}"),
+        Err(e) => println!("GF(2^8) 0x53^-1 : {e}"),
     }
+    println!("4^13 mod 497 : {:?}", modular_exponentiation(4u32, 13, 497));
+    println!("gcd(48, 18) : {}", binary_gcd(48u32, 18));
+    println!(
+        "extended gcd(240, 46) : {:?}",
+        extended_binary_gcd(240u64, 46)
+    );
+    println!("f32 0.1 + 0.2 : {}", float_adder(0.1, 0.2));
+    println!("f32 0.1 * 0.2 : {}", float_multiplier(0.1, 0.2));
+    println!("min(3, 48) : {}", branchless_min(3u32, 48));
+    println!("-5 < 2 : {}", signed_less_than(-5i32, 2));
+    println!("|-42| : {}", branchless_abs(-42i32));
+    println!("isqrt(1000000) : {}", integer_square_root(1_000_000u32));
 }
@@ -1 +0,0 @@
-use super::adder::adder;
@@ -0,0 +1,184 @@
+use crate::{
+    iterative::iterative_multiplier,
+    signed::Signed,
+    unsigned::{generic_adder, generic_multiplier, generic_subtractor, Unsigned},
+};
+
+// Below this operand width Karatsuba falls back to shift-and-add
+const KARATSUBA_THRESHOLD: u32 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MultiplicationAlgorithm {
+    ShiftAndAdd,
+    BoothRadix2,
+    BoothRadix4,
+    Karatsuba,
+}
+
+pub fn multiplier_with<T: Unsigned>(a: T, b: T, algorithm: MultiplicationAlgorithm) -> T {
+    match algorithm {
+        MultiplicationAlgorithm::ShiftAndAdd => generic_multiplier(a, b),
+        MultiplicationAlgorithm::BoothRadix2 => booth_radix2_multiplier(a, b),
+        MultiplicationAlgorithm::BoothRadix4 => booth_radix4_multiplier(a, b),
+        MultiplicationAlgorithm::Karatsuba => karatsuba_multiplier(a, b),
+    }
+}
+
+pub fn signed_multiplier_with<T: Signed>(a: T, b: T, algorithm: MultiplicationAlgorithm) -> T {
+    T::from_bits(multiplier_with(a.to_bits(), b.to_bits(), algorithm))
+}
+
+fn bit_at<T: Unsigned>(value: T, index: u32) -> bool {
+    (value >> index) & T::ONE == T::ONE
+}
+
+pub fn booth_radix2_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    let mut product = T::ZERO;
+    let mut previous_bit = false;
+    for index in 0..T::BITS {
+        let current_bit = bit_at(b, index);
+        match (current_bit, previous_bit) {
+            (true, false) => product = generic_subtractor(product, a << index),
+            (false, true) => product = generic_adder(product, a << index),
+            _ => {}
+        }
+        previous_bit = current_bit;
+    }
+    product
+}
+
+pub fn booth_radix4_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    let mut product = T::ZERO;
+    let mut previous_bit = false;
+    for index in (0..T::BITS).step_by(2) {
+        let low_bit = bit_at(b, index);
+        let high_bit = bit_at(b, index | 1);
+        let shifted_a = a << index;
+        // Recode the bit triplet into a digit in -2..=2
+        match (high_bit, low_bit, previous_bit) {
+            (false, false, true) | (false, true, false) => {
+                product = generic_adder(product, shifted_a)
+            }
+            (false, true, true) => product = generic_adder(product, shifted_a << 1),
+            (true, false, false) => product = generic_subtractor(product, shifted_a << 1),
+            (true, false, true) | (true, true, false) => {
+                product = generic_subtractor(product, shifted_a)
+            }
+            _ => {}
+        }
+        previous_bit = high_bit;
+    }
+    product
+}
+
+fn low_mask<T: Unsigned>(width: u32) -> T {
+    !(T::MAX << width)
+}
+
+// Exact product of two operands narrower than `width` bits, `width` being small
+// enough for the product of the split sums to still fit in T
+fn karatsuba_exact<T: Unsigned>(a: T, b: T, width: u32) -> T {
+    if width <= KARATSUBA_THRESHOLD {
+        return iterative_multiplier(a, b);
+    }
+    let low_width = width >> 1;
+    let high_width = generic_subtractor(width, low_width);
+    let mask = low_mask(low_width);
+    let (a_low, a_high) = (a & mask, a >> low_width);
+    let (b_low, b_high) = (b & mask, b >> low_width);
+
+    let low_product = karatsuba_exact(a_low, b_low, low_width);
+    let high_product = karatsuba_exact(a_high, b_high, high_width);
+    let sums_product = karatsuba_exact(
+        generic_adder(a_low, a_high),
+        generic_adder(b_low, b_high),
+        generic_adder(high_width, 1),
+    );
+    let middle_product =
+        generic_subtractor(generic_subtractor(sums_product, low_product), high_product);
+
+    generic_adder(
+        generic_adder(low_product, middle_product << low_width),
+        high_product << (low_width << 1),
+    )
+}
+
+// Native widths only, BigUint splits on limbs for operands of many words
+pub fn karatsuba_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    let half_width = T::BITS >> 1;
+    let mask = low_mask(half_width);
+    let (a_low, a_high) = (a & mask, a >> half_width);
+    let (b_low, b_high) = (b & mask, b >> half_width);
+
+    // The high product only lands above bit BITS, but the middle term still
+    // needs it; every term is only kept modulo 2^half_width before shifting
+    let low_product = karatsuba_exact(a_low, b_low, half_width);
+    let high_product = karatsuba_exact(a_high, b_high, half_width);
+    let sums_product = karatsuba_exact(
+        generic_adder(a_low, a_high) & mask,
+        generic_adder(b_low, b_high) & mask,
+        half_width,
+    );
+    let middle_product =
+        generic_subtractor(generic_subtractor(sums_product, low_product), high_product);
+
+    generic_adder(low_product, middle_product << half_width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [MultiplicationAlgorithm; 4] = [
+        MultiplicationAlgorithm::ShiftAndAdd,
+        MultiplicationAlgorithm::BoothRadix2,
+        MultiplicationAlgorithm::BoothRadix4,
+        MultiplicationAlgorithm::Karatsuba,
+    ];
+
+    #[test]
+    fn multiplier_with_test_ok() {
+        for algorithm in ALGORITHMS {
+            assert_eq!(multiplier_with(10u32, 5, algorithm), 50);
+            assert_eq!(multiplier_with(0u32, 15, algorithm), 0);
+            assert_eq!(multiplier_with(3u64, 48, algorithm), 144);
+            assert_eq!(
+                multiplier_with(u128::MAX >> 3, 0xDEAD_BEEF_CAFE_BABE, algorithm),
+                (u128::MAX >> 3).wrapping_mul(0xDEAD_BEEF_CAFE_BABE)
+            );
+            assert_eq!(signed_multiplier_with(-10i32, 5, algorithm), -50);
+            assert_eq!(signed_multiplier_with(-10i64, -5, algorithm), 50);
+            assert_eq!(signed_multiplier_with(i64::MIN, -1, algorithm), i64::MIN);
+        }
+    }
+
+    #[test]
+    fn multiplier_with_exhaustive_8_bits() {
+        for algorithm in ALGORITHMS {
+            for a in i8::MIN..=i8::MAX {
+                for b in i8::MIN..=i8::MAX {
+                    assert_eq!(signed_multiplier_with(a, b, algorithm), a.wrapping_mul(b));
+                    assert_eq!(
+                        multiplier_with(a as u8, b as u8, algorithm),
+                        (a as u8).wrapping_mul(b as u8)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn karatsuba_matches_std_u64() {
+        let mut value = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..1000 {
+            value ^= value << 13;
+            value ^= value >> 7;
+            value ^= value << 17;
+            let other = value.rotate_left(29);
+            assert_eq!(
+                karatsuba_multiplier(value, other),
+                value.wrapping_mul(other)
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    comparison::less_than,
+    divider::restoring_divider,
+    error::Result,
+    signed::{signed_adder, signed_subtractor, Signed, Widen},
+    unsigned::{generic_adder, generic_overflowing_adder, generic_subtractor, Unsigned},
+};
+
+// Both operands are already reduced, so at most one subtraction of the modulus is needed
+fn modular_adder<T: Unsigned>(a: T, b: T, modulus: T) -> T {
+    let (sum, carry) = generic_overflowing_adder(a, b);
+    if carry || !less_than(sum, modulus) {
+        generic_subtractor(sum, modulus)
+    } else {
+        sum
+    }
+}
+
+pub fn modular_multiplier<T: Unsigned>(a: T, b: T, modulus: T) -> Result<T> {
+    let (_, a) = restoring_divider(a, modulus)?;
+    let mut result = T::ZERO;
+    for index in (0..T::BITS).rev() {
+        result = modular_adder(result, result, modulus);
+        if (b >> index) & T::ONE == T::ONE {
+            result = modular_adder(result, a, modulus);
+        }
+    }
+    Ok(result)
+}
+
+pub fn modular_exponentiation<T: Unsigned>(base: T, exponent: T, modulus: T) -> Result<T> {
+    let (_, mut square) = restoring_divider(base, modulus)?;
+    let (_, mut result) = restoring_divider(T::ONE, modulus)?;
+    let mut remaining_exponent = exponent;
+    while remaining_exponent != T::ZERO {
+        if remaining_exponent & T::ONE == T::ONE {
+            result = modular_multiplier(result, square, modulus)?;
+        }
+        square = modular_multiplier(square, square, modulus)?;
+        remaining_exponent = remaining_exponent >> 1;
+    }
+    Ok(result)
+}
+
+fn is_even<T: Unsigned>(value: T) -> bool {
+    value & T::ONE == T::ZERO
+}
+
+pub fn binary_gcd<T: Unsigned>(a: T, b: T) -> T {
+    if a == T::ZERO {
+        return b;
+    }
+    if b == T::ZERO {
+        return a;
+    }
+    let (mut a, mut b) = (a, b);
+    let mut common_twos = 0u32;
+    while is_even(a | b) {
+        a = a >> 1;
+        b = b >> 1;
+        common_twos = generic_adder(common_twos, 1);
+    }
+    while is_even(a) {
+        a = a >> 1;
+    }
+    while b != T::ZERO {
+        while is_even(b) {
+            b = b >> 1;
+        }
+        if less_than(b, a) {
+            (a, b) = (b, a);
+        }
+        b = generic_subtractor(b, a);
+    }
+    a << common_twos
+}
+
+// Arithmetic shift, the sign bit is kept so negative coefficients stay negative
+fn halve<T: Signed>(value: T) -> T {
+    let bits = value.to_bits();
+    T::from_bits((bits >> 1) | (bits & T::Bits::HIGHEST_BIT))
+}
+
+fn is_even_signed<T: Signed>(value: T) -> bool {
+    is_even(value.to_bits())
+}
+
+// Binary extended gcd (HAC 14.61), returns (gcd, x, y) with a * x + b * y = gcd.
+// The coefficients can exceed the operands by a bit and be negative, so they
+// use the next signed width
+pub fn extended_binary_gcd<T: Widen>(a: T, b: T) -> (T, T::Wide, T::Wide) {
+    let (zero, one) = (T::ZERO.widen(), T::ONE.widen());
+    match (a == T::ZERO, b == T::ZERO) {
+        (true, true) => return (T::ZERO, zero, zero),
+        (true, false) => return (b, zero, one),
+        (false, true) => return (a, one, zero),
+        _ => {}
+    }
+    let (mut a, mut b) = (a, b);
+    let mut common_twos = 0u32;
+    while is_even(a | b) {
+        a = a >> 1;
+        b = b >> 1;
+        common_twos = generic_adder(common_twos, 1);
+    }
+    let (x, y) = (a.widen(), b.widen());
+    let (mut u, mut v) = (x, y);
+    let (mut coefficient_a, mut coefficient_b) = (one, zero);
+    let (mut coefficient_c, mut coefficient_d) = (zero, one);
+    loop {
+        while is_even_signed(u) {
+            u = halve(u);
+            if is_even_signed(coefficient_a) && is_even_signed(coefficient_b) {
+                coefficient_a = halve(coefficient_a);
+                coefficient_b = halve(coefficient_b);
+            } else {
+                coefficient_a = halve(signed_adder(coefficient_a, y));
+                coefficient_b = halve(signed_subtractor(coefficient_b, x));
+            }
+        }
+        while is_even_signed(v) {
+            v = halve(v);
+            if is_even_signed(coefficient_c) && is_even_signed(coefficient_d) {
+                coefficient_c = halve(coefficient_c);
+                coefficient_d = halve(coefficient_d);
+            } else {
+                coefficient_c = halve(signed_adder(coefficient_c, y));
+                coefficient_d = halve(signed_subtractor(coefficient_d, x));
+            }
+        }
+        if less_than(u.to_bits(), v.to_bits()) {
+            v = signed_subtractor(v, u);
+            coefficient_c = signed_subtractor(coefficient_c, coefficient_a);
+            coefficient_d = signed_subtractor(coefficient_d, coefficient_b);
+        } else {
+            u = signed_subtractor(u, v);
+            coefficient_a = signed_subtractor(coefficient_a, coefficient_c);
+            coefficient_b = signed_subtractor(coefficient_b, coefficient_d);
+        }
+        if u.to_bits() == zero.to_bits() {
+            return (T::narrow(v) << common_twos, coefficient_c, coefficient_d);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn modular_multiplier_test_ok() {
+        assert_eq!(modular_multiplier(10u32, 5, 7).unwrap(), 1);
+        assert_eq!(
+            modular_multiplier(u64::MAX, u64::MAX, u64::MAX - 58).unwrap(),
+            ((u64::MAX as u128 * u64::MAX as u128) % (u64::MAX - 58) as u128) as u64
+        );
+        assert!(matches!(
+            modular_multiplier(3u32, 4, 0),
+            Err(Error::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn modular_exponentiation_test_ok() {
+        assert_eq!(modular_exponentiation(4u32, 13, 497).unwrap(), 445);
+        assert_eq!(modular_exponentiation(2u64, 0, 1).unwrap(), 0);
+        assert_eq!(modular_exponentiation(0u64, 0, 7).unwrap(), 1);
+        // Fermat's little theorem on the largest 64 bit prime
+        let prime = 0xFFFF_FFFF_FFFF_FFC5u64;
+        assert_eq!(
+            modular_exponentiation(0xDEAD_BEEF, prime - 1, prime).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn modular_arithmetic_sampled_u8() {
+        for modulus in 1..=u8::MAX {
+            for a in (0..=u8::MAX).step_by(7) {
+                for b in (0..=u8::MAX).step_by(5) {
+                    let expected = (a as u32 * b as u32 % modulus as u32) as u8;
+                    assert_eq!(modular_multiplier(a, b, modulus).unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binary_gcd_test_ok() {
+        assert_eq!(binary_gcd(48u32, 18), 6);
+        assert_eq!(binary_gcd(0u32, 18), 18);
+        assert_eq!(binary_gcd(17u64, 0), 17);
+        assert_eq!(binary_gcd(1u8 << 7, 96), 32);
+        assert_eq!(binary_gcd(u128::MAX, u128::MAX >> 64), u128::MAX >> 64);
+    }
+
+    #[test]
+    fn extended_binary_gcd_test_ok() {
+        for (a, b) in [
+            (240u64, 46),
+            (46, 240),
+            (0, 9),
+            (9, 0),
+            (17, 17),
+            (1 << 40, 3 << 20),
+            (u64::MAX, 0xFFFF_FFFF_FFFF_FFC5),
+        ] {
+            let (gcd, x, y) = extended_binary_gcd(a, b);
+            assert_eq!(gcd, binary_gcd(a, b));
+            assert_eq!(a as i128 * x + b as i128 * y, gcd as i128, "{a} {b}");
+        }
+    }
+
+    #[test]
+    fn extended_binary_gcd_exhaustive_u8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let (gcd, x, y) = extended_binary_gcd(a, b);
+                assert_eq!(gcd, binary_gcd(a, b));
+                assert_eq!(
+                    a as i32 * x as i32 + b as i32 * y as i32,
+                    gcd as i32,
+                    "{a} {b}"
+                );
+            }
+        }
+        let (gcd, x, y) = extended_binary_gcd(0xFFFF_FFFBu32, 0xFFFF_FFFE);
+        assert_eq!(gcd, 1);
+        assert_eq!(0xFFFF_FFFBi64 * x + 0xFFFF_FFFEi64 * y, 1);
+    }
+}
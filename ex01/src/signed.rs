@@ -0,0 +1,134 @@
+use crate::unsigned::{generic_adder, generic_multiplier, generic_negate, Unsigned};
+
+pub trait Signed: Copy {
+    type Bits: Unsigned;
+
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($signed_type:ty => $unsigned_type:ty),*) => {
+        $(
+            impl Signed for $signed_type {
+                type Bits = $unsigned_type;
+
+                fn to_bits(self) -> Self::Bits {
+                    self as $unsigned_type
+                }
+
+                fn from_bits(bits: Self::Bits) -> Self {
+                    bits as $signed_type
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+// Unsigned widths paired with the next signed width up, for results that need
+// both a sign and one more bit than the operands
+pub trait Widen: Unsigned {
+    type Wide: Signed;
+
+    fn widen(self) -> Self::Wide;
+    fn narrow(wide: Self::Wide) -> Self;
+}
+
+macro_rules! impl_widen {
+    ($($unsigned_type:ty => $signed_type:ty),*) => {
+        $(
+            impl Widen for $unsigned_type {
+                type Wide = $signed_type;
+
+                fn widen(self) -> Self::Wide {
+                    self as $signed_type
+                }
+
+                fn narrow(wide: Self::Wide) -> Self {
+                    wide as $unsigned_type
+                }
+            }
+        )*
+    };
+}
+
+// u128 has no wider signed type to pair with
+impl_widen!(u8 => i16, u16 => i32, u32 => i64, u64 => i128);
+
+pub fn is_negative<T: Signed>(value: T) -> bool {
+    value.to_bits() & T::Bits::HIGHEST_BIT != T::Bits::ZERO
+}
+
+fn magnitude<T: Signed>(value: T) -> T::Bits {
+    if is_negative(value) {
+        generic_negate(value.to_bits())
+    } else {
+        value.to_bits()
+    }
+}
+
+pub fn signed_adder<T: Signed>(a: T, b: T) -> T {
+    T::from_bits(generic_adder(a.to_bits(), b.to_bits()))
+}
+
+pub fn signed_negate<T: Signed>(a: T) -> T {
+    T::from_bits(generic_negate(a.to_bits()))
+}
+
+pub fn signed_subtractor<T: Signed>(a: T, b: T) -> T {
+    signed_adder(a, signed_negate(b))
+}
+
+pub fn signed_multiplier<T: Signed>(a: T, b: T) -> T {
+    let product = generic_multiplier(magnitude(a), magnitude(b));
+    if is_negative(a) != is_negative(b) {
+        T::from_bits(generic_negate(product))
+    } else {
+        T::from_bits(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_adder_test_ok() {
+        assert_eq!(signed_adder(10i32, -5), 5);
+        assert_eq!(signed_adder(-10i32, -5), -15);
+        assert_eq!(signed_adder(i32::MAX, 1), i32::MIN);
+        assert_eq!(signed_adder(-3i64, 48), 45);
+        assert_eq!(signed_adder(i64::MIN, -1), i64::MAX);
+    }
+
+    #[test]
+    fn signed_subtractor_test_ok() {
+        assert_eq!(signed_subtractor(5i32, 10), -5);
+        assert_eq!(signed_subtractor(-5i64, -10), 5);
+        assert_eq!(signed_subtractor(i32::MIN, 1), i32::MAX);
+        assert_eq!(signed_negate(i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn signed_multiplier_test_ok() {
+        assert_eq!(signed_multiplier(-10i32, 5), -50);
+        assert_eq!(signed_multiplier(-10i32, -5), 50);
+        assert_eq!(signed_multiplier(3i64, -48), -144);
+        assert_eq!(signed_multiplier(-13i64, 0), 0);
+        assert_eq!(signed_multiplier(i32::MIN, -1), i32::MIN);
+        assert_eq!(signed_multiplier(i64::MAX, -3), i64::MAX.wrapping_mul(-3));
+    }
+
+    #[test]
+    fn signed_arithmetic_exhaustive_i8() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                assert_eq!(signed_adder(a, b), a.wrapping_add(b));
+                assert_eq!(signed_subtractor(a, b), a.wrapping_sub(b));
+                assert_eq!(signed_multiplier(a, b), a.wrapping_mul(b));
+            }
+        }
+    }
+}
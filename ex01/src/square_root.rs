@@ -0,0 +1,49 @@
+use crate::{
+    comparison::{greater_or_equal, greater_than},
+    unsigned::{generic_adder, generic_subtractor, Unsigned},
+};
+
+// Binary digit-by-digit method, one result bit per pair of input bits
+pub fn integer_square_root<T: Unsigned>(value: T) -> T {
+    let mut remainder = value;
+    let mut root = T::ZERO;
+    let mut bit = T::HIGHEST_BIT >> 1;
+    while greater_than(bit, value) {
+        bit = bit >> 2;
+    }
+    while bit != T::ZERO {
+        let candidate = generic_adder(root, bit);
+        if greater_or_equal(remainder, candidate) {
+            remainder = generic_subtractor(remainder, candidate);
+            root = generic_adder(root >> 1, bit);
+        } else {
+            root = root >> 1;
+        }
+        bit = bit >> 2;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_square_root_test_ok() {
+        assert_eq!(integer_square_root(0u32), 0);
+        assert_eq!(integer_square_root(1u32), 1);
+        assert_eq!(integer_square_root(144u32), 12);
+        assert_eq!(integer_square_root(143u32), 11);
+        assert_eq!(integer_square_root(u64::MAX), u32::MAX as u64);
+        assert_eq!(integer_square_root(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn integer_square_root_exhaustive_u16() {
+        for value in 0..=u16::MAX {
+            let root = integer_square_root(value) as u32;
+            assert!(root * root <= value as u32);
+            assert!((root + 1) * (root + 1) > value as u32);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::adder::adder;
+
+pub fn negate(a: u32) -> u32 {
+    adder(!a, 1)
+}
+
+pub fn subtractor(a: u32, b: u32) -> u32 {
+    adder(a, negate(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn negate_test_ok() {
+        assert_eq!(negate(0), 0);
+        assert_eq!(negate(1), u32::MAX);
+        assert_eq!(negate(0x8000_0000), 0x8000_0000);
+    }
+
+    #[test]
+    fn subtractor_test_ok() {
+        assert_eq!(subtractor(15, 5), 10);
+        assert_eq!(subtractor(15, 0), 15);
+        assert_eq!(subtractor(48, 48), 0);
+        assert_eq!(subtractor(0, 1), u32::MAX);
+        assert_eq!(subtractor(3, 48), 3u32.wrapping_sub(48));
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt;
+
+use crate::unsigned::{generic_adder, Unsigned};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdderStep<T> {
+    pub a: T,
+    pub b: T,
+    pub partial_sum: T,
+    pub carry: T,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdderTrace<T> {
+    pub steps: Vec<AdderStep<T>>,
+    pub result: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultiplierStep<T> {
+    pub shifted_a: T,
+    pub remaining_b: T,
+    pub partial_product: T,
+    pub running_total: T,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiplierTrace<T> {
+    pub steps: Vec<MultiplierStep<T>>,
+    pub result: T,
+}
+
+pub fn trace_adder<T: Unsigned>(a: T, b: T) -> AdderTrace<T> {
+    let mut steps = vec![];
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        let step = AdderStep {
+            a,
+            b,
+            partial_sum: a ^ b,
+            carry: (a & b) << 1,
+        };
+        steps.push(step);
+        a = step.partial_sum;
+        b = step.carry;
+    }
+    AdderTrace { steps, result: a }
+}
+
+pub fn trace_multiplier<T: Unsigned>(a: T, b: T) -> MultiplierTrace<T> {
+    let mut steps = vec![];
+    let (mut shifted_a, mut remaining_b) = (a, b);
+    let mut running_total = T::ZERO;
+    while remaining_b != T::ZERO {
+        let partial_product = if remaining_b & T::ONE == T::ONE {
+            shifted_a
+        } else {
+            T::ZERO
+        };
+        running_total = generic_adder(running_total, partial_product);
+        steps.push(MultiplierStep {
+            shifted_a,
+            remaining_b,
+            partial_product,
+            running_total,
+        });
+        shifted_a = shifted_a << 1;
+        remaining_b = remaining_b >> 1;
+    }
+    MultiplierTrace {
+        steps,
+        result: running_total,
+    }
+}
+
+fn write_table<T: Unsigned>(
+    f: &mut fmt::Formatter<'_>,
+    headers: [&str; 4],
+    rows: impl Iterator<Item = [T; 4]>,
+    result: T,
+) -> fmt::Result {
+    let bits = T::BITS as usize;
+    let widths = headers.map(|header| header.len().max(bits));
+
+    write!(f, "| step ")?;
+    for (header, width) in headers.iter().zip(widths) {
+        write!(f, "| {header:<width$} ")?;
+    }
+    writeln!(f, "|")?;
+    write!(f, "|------")?;
+    for width in widths {
+        write!(f, "|{}", "-".repeat(width + 2))?;
+    }
+    writeln!(f, "|")?;
+    for (index, row) in rows.enumerate() {
+        write!(f, "| {index:>4} ")?;
+        for (value, width) in row.iter().zip(widths) {
+            let cell = format!("{value:0bits$b}");
+            write!(f, "| {cell:<width$} ")?;
+        }
+        writeln!(f, "|")?;
+    }
+    write!(f, "result: {result:0bits$b}")
+}
+
+impl<T: Unsigned> fmt::Display for AdderTrace<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_table(
+            f,
+            ["a", "b", "a ^ b", "(a & b) << 1"],
+            self.steps
+                .iter()
+                .map(|step| [step.a, step.b, step.partial_sum, step.carry]),
+            self.result,
+        )
+    }
+}
+
+impl<T: Unsigned> fmt::Display for MultiplierTrace<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_table(
+            f,
+            ["a << step", "b >> step", "partial product", "running total"],
+            self.steps.iter().map(|step| {
+                [
+                    step.shifted_a,
+                    step.remaining_b,
+                    step.partial_product,
+                    step.running_total,
+                ]
+            }),
+            self.result,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unsigned::generic_multiplier;
+
+    #[test]
+    fn trace_adder_test_ok() {
+        let trace = trace_adder(0b0111u8, 0b0001);
+        assert_eq!(trace.result, 8);
+        assert_eq!(
+            trace.steps,
+            vec![
+                AdderStep {
+                    a: 0b0111,
+                    b: 0b0001,
+                    partial_sum: 0b0110,
+                    carry: 0b0010
+                },
+                AdderStep {
+                    a: 0b0110,
+                    b: 0b0010,
+                    partial_sum: 0b0100,
+                    carry: 0b0100
+                },
+                AdderStep {
+                    a: 0b0100,
+                    b: 0b0100,
+                    partial_sum: 0b0000,
+                    carry: 0b1000
+                },
+                AdderStep {
+                    a: 0b0000,
+                    b: 0b1000,
+                    partial_sum: 0b1000,
+                    carry: 0b0000
+                },
+            ]
+        );
+        assert!(trace_adder(13u32, 0).steps.is_empty());
+    }
+
+    #[test]
+    fn trace_multiplier_test_ok() {
+        let trace = trace_multiplier(3u8, 0b101);
+        assert_eq!(trace.result, 15);
+        let partial_products: Vec<u8> = trace
+            .steps
+            .iter()
+            .map(|step| step.partial_product)
+            .collect();
+        assert_eq!(partial_products, vec![3, 0, 12]);
+        for (a, b) in [(10u32, 5), (0, 15), (3, 48), (13, 0), (u32::MAX, 3)] {
+            assert_eq!(trace_multiplier(a, b).result, generic_multiplier(a, b));
+            assert_eq!(trace_adder(a, b).result, generic_adder(a, b));
+        }
+    }
+
+    #[test]
+    fn trace_display_ok() {
+        let table = trace_adder(0b0011u8, 0b0001).to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[0],
+            "| step | a        | b        | a ^ b    | (a & b) << 1 |"
+        );
+        assert_eq!(
+            lines[2],
+            "|    0 | 00000011 | 00000001 | 00000010 | 00000010     |"
+        );
+        assert_eq!(lines[5], "result: 00000100");
+        let table = trace_multiplier(3u8, 2).to_string();
+        assert!(
+            table.contains("|    1 | 00000110  | 00000001  | 00000110        | 00000110      |")
+        );
+    }
+}
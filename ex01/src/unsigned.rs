@@ -0,0 +1,128 @@
+use std::{
+    fmt::{Binary, Debug},
+    ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr},
+};
+
+pub trait Unsigned:
+    Copy
+    + Debug
+    + Binary
+    + Eq
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+    const HIGHEST_BIT: Self;
+    const BITS: u32;
+
+    fn count_ones(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+}
+
+macro_rules! impl_unsigned {
+    ($($unsigned_type:ty),*) => {
+        $(
+            impl Unsigned for $unsigned_type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$unsigned_type>::MAX;
+                const HIGHEST_BIT: Self = 1 << (<$unsigned_type>::BITS - 1);
+                const BITS: u32 = <$unsigned_type>::BITS;
+
+                fn count_ones(self) -> u32 {
+                    <$unsigned_type>::count_ones(self)
+                }
+
+                fn trailing_zeros(self) -> u32 {
+                    <$unsigned_type>::trailing_zeros(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+pub fn generic_adder<T: Unsigned>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        return a;
+    }
+    let carry = (a & b) << 1;
+
+    generic_adder(a ^ b, carry)
+}
+
+pub fn generic_overflowing_adder<T: Unsigned>(a: T, b: T) -> (T, bool) {
+    if b == T::ZERO {
+        return (a, false);
+    }
+    let carry_out = (a & b) & T::HIGHEST_BIT != T::ZERO;
+    let carry = (a & b) << 1;
+
+    let (sum, overflowed) = generic_overflowing_adder(a ^ b, carry);
+    (sum, carry_out || overflowed)
+}
+
+pub fn generic_negate<T: Unsigned>(a: T) -> T {
+    generic_adder(!a, T::ONE)
+}
+
+pub fn generic_subtractor<T: Unsigned>(a: T, b: T) -> T {
+    generic_adder(a, generic_negate(b))
+}
+
+pub fn generic_multiplier<T: Unsigned>(a: T, b: T) -> T {
+    if b == T::ZERO {
+        return T::ZERO;
+    }
+    let partial_product = if b & T::ONE == T::ONE { a } else { T::ZERO };
+
+    generic_adder(partial_product, generic_multiplier(a << 1, b >> 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generic_adder_test_ok() {
+        assert_eq!(generic_adder(10u8, 5), 15);
+        assert_eq!(generic_adder(0u16, 15), 15);
+        assert_eq!(generic_adder(3u32, 48), 51);
+        assert_eq!(generic_adder(13u64, 0), 13);
+        assert_eq!(generic_adder(u64::MAX as u128, 1), 1u128 << 64);
+        assert_eq!(generic_adder(usize::MAX, 1), 0);
+        assert_eq!(generic_adder(u8::MAX, u8::MAX), 254);
+    }
+
+    #[test]
+    fn generic_multiplier_test_ok() {
+        assert_eq!(generic_multiplier(10u8, 5), 50);
+        assert_eq!(generic_multiplier(0u16, 15), 0);
+        assert_eq!(generic_multiplier(3u32, 48), 144);
+        assert_eq!(generic_multiplier(13u64, 0), 0);
+        assert_eq!(
+            generic_multiplier(u64::MAX as u128, u64::MAX as u128),
+            (u64::MAX as u128).wrapping_mul(u64::MAX as u128)
+        );
+        assert_eq!(generic_multiplier(200u8, 3), 200u8.wrapping_mul(3));
+    }
+
+    #[test]
+    fn generic_arithmetic_exhaustive_u8() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                assert_eq!(generic_adder(a, b), a.wrapping_add(b));
+                assert_eq!(generic_overflowing_adder(a, b), a.overflowing_add(b));
+                assert_eq!(generic_subtractor(a, b), a.wrapping_sub(b));
+                assert_eq!(generic_multiplier(a, b), a.wrapping_mul(b));
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use ex01::signed::{signed_adder, signed_subtractor};
+
+use crate::gray_code::{gray_decode, gray_encode};
+
+const QUADRATURE_BITS: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncoderEvent {
+    Idle,
+    Step(Direction),
+    // A step straight back to the reading before the previous one
+    Bounce(Direction),
+    // Direction is None when both ways around are the same distance
+    SkippedStates {
+        skipped: u64,
+        direction: Option<Direction>,
+    },
+    InvalidReading(u64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncoderReport {
+    pub position: i64,
+    pub absolute_position: Option<u64>,
+    pub direction: Option<Direction>,
+    pub bounces: usize,
+    pub invalid_transitions: usize,
+    pub events: Vec<EncoderEvent>,
+}
+
+// Decodes Gray-coded samples from an n-bit absolute encoder, a quadrature
+// encoder being the 2-bit case where only the accumulated steps matter
+#[derive(Debug, Clone)]
+pub struct EncoderDecoder {
+    bits: u32,
+    previous_reading: Option<u64>,
+    reading_before_previous: Option<u64>,
+    position: i64,
+    direction: Option<Direction>,
+}
+
+impl EncoderDecoder {
+    pub fn absolute(bits: u32) -> Self {
+        assert!(
+            (1..u64::BITS).contains(&bits),
+            "Encoder width should be between 1 and 63 bits"
+        );
+        Self {
+            bits,
+            previous_reading: None,
+            reading_before_previous: None,
+            position: 0,
+            direction: None,
+        }
+    }
+
+    pub fn quadrature() -> Self {
+        Self::absolute(QUADRATURE_BITS)
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    pub fn absolute_position(&self) -> Option<u64> {
+        self.previous_reading.map(gray_decode)
+    }
+
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    pub fn sample(&mut self, reading: u64) -> EncoderEvent {
+        if reading >> self.bits != 0 {
+            return EncoderEvent::InvalidReading(reading);
+        }
+        let Some(previous_reading) = self.previous_reading else {
+            self.previous_reading = Some(reading);
+            return EncoderEvent::Idle;
+        };
+        if reading == previous_reading {
+            return EncoderEvent::Idle;
+        }
+
+        let states = 1u64 << self.bits;
+        let forward_distance =
+            gray_decode(reading).wrapping_sub(gray_decode(previous_reading)) & (states - 1);
+        let backward_distance = states - forward_distance;
+        let (distance, direction) = match forward_distance.cmp(&backward_distance) {
+            std::cmp::Ordering::Less => (forward_distance, Some(Direction::Forward)),
+            std::cmp::Ordering::Greater => (backward_distance, Some(Direction::Backward)),
+            std::cmp::Ordering::Equal => (forward_distance, None),
+        };
+
+        let event = match direction {
+            Some(direction) if distance == 1 => {
+                let is_bounce = self.direction.is_some_and(|previous| previous != direction)
+                    && self.reading_before_previous == Some(reading);
+                if is_bounce {
+                    EncoderEvent::Bounce(direction)
+                } else {
+                    EncoderEvent::Step(direction)
+                }
+            }
+            _ => EncoderEvent::SkippedStates {
+                skipped: distance - 1,
+                direction,
+            },
+        };
+        match direction {
+            Some(Direction::Forward) => {
+                self.position = signed_adder(self.position, distance as i64)
+            }
+            Some(Direction::Backward) => {
+                self.position = signed_subtractor(self.position, distance as i64)
+            }
+            None => {}
+        }
+        if direction.is_some() {
+            self.direction = direction;
+        }
+        self.reading_before_previous = Some(previous_reading);
+        self.previous_reading = Some(reading);
+        event
+    }
+
+    pub fn decode_stream(&mut self, readings: &[u64]) -> EncoderReport {
+        let events: Vec<EncoderEvent> = readings
+            .iter()
+            .map(|&reading| self.sample(reading))
+            .collect();
+        EncoderReport {
+            position: self.position,
+            absolute_position: self.absolute_position(),
+            direction: self.direction,
+            bounces: events
+                .iter()
+                .filter(|event| matches!(event, EncoderEvent::Bounce(_)))
+                .count(),
+            invalid_transitions: events
+                .iter()
+                .filter(|event| {
+                    matches!(
+                        event,
+                        EncoderEvent::SkippedStates { .. } | EncoderEvent::InvalidReading(_)
+                    )
+                })
+                .count(),
+            events,
+        }
+    }
+}
+
+// Gray-coded readings of an encoder turning through the given absolute positions,
+// empty for a width that cannot hold a reading
+pub fn encoder_readings(bits: u32, positions: &[u64]) -> Vec<u64> {
+    if !(1..=u64::BITS).contains(&bits) {
+        return vec![];
+    }
+    let mask = u64::MAX >> (u64::BITS - bits);
+    positions
+        .iter()
+        .map(|&position| gray_encode(position & mask))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quadrature_decoder_test_ok() {
+        let mut decoder = EncoderDecoder::quadrature();
+        // A then B leading: 00 -> 01 -> 11 -> 10 -> 00 is one forward cycle
+        let report = decoder.decode_stream(&[0b00, 0b01, 0b11, 0b10, 0b00, 0b01]);
+        assert_eq!(report.position, 5);
+        assert_eq!(report.direction, Some(Direction::Forward));
+        assert_eq!(report.invalid_transitions, 0);
+
+        let report = decoder.decode_stream(&[0b00, 0b10, 0b11]);
+        assert_eq!(report.position, 2);
+        assert_eq!(report.direction, Some(Direction::Backward));
+    }
+
+    #[test]
+    fn quadrature_decoder_bounce_and_skip() {
+        let mut decoder = EncoderDecoder::quadrature();
+        let report = decoder.decode_stream(&[0b00, 0b01, 0b00, 0b01, 0b10]);
+        assert_eq!(
+            report.events,
+            vec![
+                EncoderEvent::Idle,
+                EncoderEvent::Step(Direction::Forward),
+                EncoderEvent::Bounce(Direction::Backward),
+                EncoderEvent::Bounce(Direction::Forward),
+                EncoderEvent::SkippedStates {
+                    skipped: 1,
+                    direction: None
+                },
+            ]
+        );
+        assert_eq!(report.position, 1);
+        assert_eq!(report.bounces, 2);
+        assert_eq!(report.invalid_transitions, 1);
+        assert_eq!(decoder.sample(0b100), EncoderEvent::InvalidReading(0b100));
+    }
+
+    #[test]
+    fn absolute_encoder_test_ok() {
+        let mut decoder = EncoderDecoder::absolute(4);
+        let readings = encoder_readings(4, &[14, 15, 0, 1, 2, 1]);
+        let report = decoder.decode_stream(&readings);
+        assert_eq!(report.position, 3);
+        assert_eq!(report.absolute_position, Some(1));
+        assert_eq!(report.invalid_transitions, 0);
+
+        assert_eq!(encoder_readings(0, &[1, 5, 2]), Vec::<u64>::new());
+        assert_eq!(encoder_readings(65, &[1]), Vec::<u64>::new());
+        assert_eq!(encoder_readings(64, &[u64::MAX]), vec![1 << 63]);
+        let readings = encoder_readings(4, &[1, 5, 2]);
+        let report = decoder.decode_stream(&readings);
+        assert_eq!(
+            report.events[1],
+            EncoderEvent::SkippedStates {
+                skipped: 3,
+                direction: Some(Direction::Forward)
+            }
+        );
+        assert_eq!(
+            report.events[2],
+            EncoderEvent::SkippedStates {
+                skipped: 2,
+                direction: Some(Direction::Backward)
+            }
+        );
+        assert_eq!(report.position, 4);
+        assert_eq!(report.absolute_position, Some(2));
+    }
+}
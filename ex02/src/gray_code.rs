@@ -0,0 +1,125 @@
+use ex01::unsigned::Unsigned;
+
+pub fn gray_code(n: u32) -> u32 {
+    n ^ (n >> 1)
+}
+
+pub fn gray_encode<T: Unsigned>(value: T) -> T {
+    value ^ (value >> 1)
+}
+
+// Each binary bit is the xor of every gray bit above it, computed as a prefix
+// xor with doubling shift distances
+pub fn gray_decode<T: Unsigned>(gray: T) -> T {
+    let mut value = gray;
+    let mut shift = 1;
+    while shift < T::BITS {
+        value = value ^ (value >> shift);
+        shift <<= 1;
+    }
+    value
+}
+
+// Bit flipping between the codes at `index` and `index + 1` of the n-bit
+// sequence, wrapping from the last code back to the first
+pub fn gray_transition_bit(bits: u32, index: u64) -> u32 {
+    assert!(
+        (1..=u64::BITS).contains(&bits),
+        "Gray sequence width should be between 1 and 64 bits"
+    );
+    index.wrapping_add(1).trailing_zeros().min(bits - 1)
+}
+
+pub fn changed_bit<T: Unsigned>(previous: T, next: T) -> Option<u32> {
+    let difference = previous ^ next;
+    match difference.count_ones() {
+        1 => Some(difference.trailing_zeros()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraySequence {
+    bits: u32,
+    next_index: Option<u64>,
+}
+
+impl GraySequence {
+    pub fn new(bits: u32) -> Self {
+        assert!(
+            (1..=u64::BITS).contains(&bits),
+            "Gray sequence width should be between 1 and 64 bits"
+        );
+        Self {
+            bits,
+            next_index: Some(0),
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+}
+
+impl Iterator for GraySequence {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_index?;
+        let last_index = u64::MAX >> (u64::BITS - self.bits);
+        self.next_index = if index == last_index {
+            None
+        } else {
+            Some(index + 1)
+        };
+        Some(gray_encode(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn gray_code_test_ok() {
+        assert_eq!(gray_code(0), 0);
+        assert_eq!(gray_code(1), 1);
+        assert_eq!(gray_code(2), 3);
+        assert_eq!(gray_code(3), 2);
+        assert_eq!(gray_code(4), 6);
+        assert_eq!(gray_code(5), 7);
+        assert_eq!(gray_code(6), 5);
+        assert_eq!(gray_code(7), 4);
+        assert_eq!(gray_code(8), 12);
+    }
+
+    #[test]
+    fn gray_decode_test_ok() {
+        for value in 0..=u16::MAX {
+            assert_eq!(gray_decode(gray_encode(value)), value);
+            assert_eq!(gray_encode(value as u32), gray_code(value as u32));
+        }
+        assert_eq!(gray_decode(gray_encode(u128::MAX - 7)), u128::MAX - 7);
+        assert_eq!(gray_decode(gray_encode(0xA5u8)), 0xA5);
+        assert_eq!(gray_decode(gray_encode(usize::MAX)), usize::MAX);
+    }
+
+    #[test]
+    fn gray_sequence_test_ok() {
+        let sequence: Vec<u64> = GraySequence::new(3).collect();
+        assert_eq!(sequence, vec![0, 1, 3, 2, 6, 7, 5, 4]);
+        assert_eq!(GraySequence::new(10).count(), 1024);
+        assert_eq!(GraySequence::new(64).nth(5), Some(7));
+    }
+
+    #[test]
+    fn gray_transition_bit_test_ok() {
+        let sequence: Vec<u64> = GraySequence::new(5).collect();
+        for (index, code) in sequence.iter().enumerate() {
+            let next = sequence[(index + 1) % sequence.len()];
+            let bit = gray_transition_bit(5, index as u64);
+            assert_eq!(changed_bit(*code, next), Some(bit));
+        }
+        assert_eq!(changed_bit(0b0110u8, 0b0101), None);
+        assert_eq!(changed_bit(0b0110u8, 0b0110), None);
+    }
+}
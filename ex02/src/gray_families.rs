@@ -0,0 +1,378 @@
+use std::collections::{HashSet, VecDeque};
+
+// Backtracking searches over the n-cube are only practical for small widths
+pub const MAX_SEARCH_BITS: u32 = 6;
+
+fn assert_search_bits(bits: u32) {
+    assert!(
+        (1..=MAX_SEARCH_BITS).contains(&bits),
+        "Searched Gray code families only support 1 to {MAX_SEARCH_BITS} bits"
+    );
+}
+
+// Base-k reflected Gray code, digits stored least significant first. A digit is
+// reflected whenever the sum of the Gray digits above it is odd
+pub fn n_ary_gray_code(base: u32, digits: u32) -> Vec<Vec<u32>> {
+    assert!(base >= 2, "Gray code base should be at least 2");
+    let length = base
+        .checked_pow(digits)
+        .expect("n-ary Gray code length should fit in a u32");
+    (0..length)
+        .map(|index| {
+            let mut remaining = index;
+            let plain_digits: Vec<u32> = (0..digits)
+                .map(|_| {
+                    let digit = remaining % base;
+                    remaining /= base;
+                    digit
+                })
+                .collect();
+            let mut higher_gray_digits_sum = 0;
+            let mut gray_digits = vec![0; digits as usize];
+            for position in (0..digits as usize).rev() {
+                gray_digits[position] = if higher_gray_digits_sum % 2 == 0 {
+                    plain_digits[position]
+                } else {
+                    base - 1 - plain_digits[position]
+                };
+                higher_gray_digits_sum += gray_digits[position];
+            }
+            gray_digits
+        })
+        .collect()
+}
+
+pub fn is_n_ary_gray_code(codes: &[Vec<u32>], base: u32, digits: u32, cyclic: bool) -> bool {
+    let all_codes_valid = codes
+        .iter()
+        .all(|code| code.len() == digits as usize && code.iter().all(|&digit| digit < base));
+    let distinct_codes: HashSet<&Vec<u32>> = codes.iter().collect();
+    let single_change = |first: &Vec<u32>, second: &Vec<u32>| {
+        first.iter().zip(second).filter(|(a, b)| a != b).count() == 1
+    };
+    let closing_pair = cyclic.then(|| (codes.last(), codes.first()));
+
+    all_codes_valid
+        && base.checked_pow(digits).map(|length| length as usize) == Some(codes.len())
+        && distinct_codes.len() == codes.len()
+        && codes
+            .windows(2)
+            .all(|pair| single_change(&pair[0], &pair[1]))
+        && match closing_pair {
+            Some((Some(last), Some(first))) => single_change(last, first),
+            _ => true,
+        }
+}
+
+// Binary codes visiting every n-bit value once, each step flipping a single bit
+pub fn is_binary_gray_code(codes: &[u64], bits: u32, cyclic: bool) -> bool {
+    let distinct_codes: HashSet<&u64> = codes.iter().collect();
+    let closing_pair = cyclic.then(|| (codes.last(), codes.first()));
+    // Widths of 64 bits or more have more codes than any slice can hold
+    1u64.checked_shl(bits) == Some(codes.len() as u64)
+        && codes
+            .iter()
+            .all(|&code| code.checked_shr(bits).unwrap_or(0) == 0)
+        && distinct_codes.len() == codes.len()
+        && codes
+            .windows(2)
+            .all(|pair| (pair[0] ^ pair[1]).count_ones() == 1)
+        && match closing_pair {
+            Some((Some(last), Some(first))) => (last ^ first).count_ones() == 1,
+            _ => true,
+        }
+}
+
+pub fn transition_counts(codes: &[u64], bits: u32) -> Vec<usize> {
+    let mut counts = vec![0; bits as usize];
+    let closing_pair = [*codes.last().unwrap_or(&0), *codes.first().unwrap_or(&0)];
+    for pair in codes.windows(2).chain([&closing_pair[..]]) {
+        let difference = pair[0] ^ pair[1];
+        if let Some(count) = counts.get_mut(difference.trailing_zeros() as usize) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+pub fn is_balanced_gray_code(codes: &[u64], bits: u32) -> bool {
+    if !is_binary_gray_code(codes, bits, true) {
+        return false;
+    }
+    let counts = transition_counts(codes, bits);
+    let spread = counts.iter().max().unwrap_or(&0) - counts.iter().min().unwrap_or(&0);
+    spread <= 2
+}
+
+fn extend_balanced(
+    path: &mut Vec<u64>,
+    visited: &mut [bool],
+    counts: &mut [usize],
+    bits: u32,
+    cap: usize,
+) -> bool {
+    let current = *path.last().expect("The search always starts from code 0");
+    if path.len() == visited.len() {
+        return current.count_ones() == 1 && is_balanced_gray_code(path, bits);
+    }
+    let mut candidate_bits: Vec<u32> = (0..bits).collect();
+    candidate_bits.sort_by_key(|&bit| counts[bit as usize]);
+    for bit in candidate_bits {
+        let next = current ^ (1 << bit);
+        if visited[next as usize] || counts[bit as usize] >= cap {
+            continue;
+        }
+        visited[next as usize] = true;
+        counts[bit as usize] += 1;
+        path.push(next);
+        if extend_balanced(path, visited, counts, bits, cap) {
+            return true;
+        }
+        path.pop();
+        counts[bit as usize] -= 1;
+        visited[next as usize] = false;
+    }
+    false
+}
+
+// Cyclic code whose per-bit transition counts differ by at most two
+pub fn balanced_gray_code(bits: u32) -> Option<Vec<u64>> {
+    assert_search_bits(bits);
+    let length = 1usize << bits;
+    // Transition counts of a cyclic code are even, the largest one is at most
+    // the average rounded up to the next even number
+    let cap = length.div_ceil(bits as usize).next_multiple_of(2).max(2);
+    let mut visited = vec![false; length];
+    visited[0] = true;
+    let mut counts = vec![0; bits as usize];
+    let mut path = vec![0];
+    if bits == 1 {
+        return Some(vec![0, 1]);
+    }
+    extend_balanced(&mut path, &mut visited, &mut counts, bits, cap).then_some(path)
+}
+
+fn weight_of(code: u64) -> usize {
+    code.count_ones() as usize
+}
+
+// Monotone: no code of weight w + 2 is visited while a code of weight w remains
+pub fn is_monotone_gray_code(codes: &[u64], bits: u32) -> bool {
+    if !is_binary_gray_code(codes, bits, false) {
+        return false;
+    }
+    let mut remaining = vec![0usize; bits as usize + 1];
+    for &code in codes {
+        remaining[weight_of(code)] += 1;
+    }
+    codes.iter().all(|&code| {
+        let weight = weight_of(code);
+        remaining[weight] -= 1;
+        remaining[..weight.saturating_sub(1)]
+            .iter()
+            .all(|&count| count == 0)
+    })
+}
+
+fn extend_monotone(
+    path: &mut Vec<u64>,
+    visited: &mut [bool],
+    remaining: &mut [usize],
+    bits: u32,
+) -> bool {
+    if path.len() == visited.len() {
+        return true;
+    }
+    let current = *path.last().expect("The search always starts from code 0");
+    for bit in 0..bits {
+        let next = current ^ (1 << bit);
+        let weight = weight_of(next);
+        if visited[next as usize]
+            || remaining[..weight.saturating_sub(1)]
+                .iter()
+                .any(|&count| count > 0)
+        {
+            continue;
+        }
+        visited[next as usize] = true;
+        remaining[weight] -= 1;
+        path.push(next);
+        if extend_monotone(path, visited, remaining, bits) {
+            return true;
+        }
+        path.pop();
+        remaining[weight] += 1;
+        visited[next as usize] = false;
+    }
+    false
+}
+
+pub fn monotone_gray_code(bits: u32) -> Option<Vec<u64>> {
+    assert_search_bits(bits);
+    let length = 1usize << bits;
+    let mut visited = vec![false; length];
+    visited[0] = true;
+    let mut remaining = vec![0usize; bits as usize + 1];
+    for code in 1..length as u64 {
+        remaining[weight_of(code)] += 1;
+    }
+    let mut path = vec![0];
+    extend_monotone(&mut path, &mut visited, &mut remaining, bits).then_some(path)
+}
+
+// Beckett-Gray: cyclic code from 0 where a bit is only ever cleared when it is
+// the bit that has been set for the longest time
+pub fn is_beckett_gray_code(codes: &[u64], bits: u32) -> bool {
+    if !is_binary_gray_code(codes, bits, true) || codes.first() != Some(&0) {
+        return false;
+    }
+    let mut set_bits = VecDeque::new();
+    let closing_pair = [*codes.last().unwrap_or(&0), 0];
+    let follows_queue = codes.windows(2).chain([&closing_pair[..]]).all(|pair| {
+        let bit = (pair[0] ^ pair[1]).trailing_zeros();
+        if pair[1] & (1 << bit) != 0 {
+            set_bits.push_back(bit);
+            true
+        } else {
+            set_bits.pop_front() == Some(bit)
+        }
+    });
+    follows_queue
+}
+
+fn extend_beckett(
+    path: &mut Vec<u64>,
+    visited: &mut [bool],
+    set_bits: &mut VecDeque<u32>,
+    bits: u32,
+) -> bool {
+    let current = *path.last().expect("The search always starts from code 0");
+    if path.len() == visited.len() {
+        // Closing back to 0 clears the last remaining bit, which is trivially the oldest
+        return set_bits.len() == 1;
+    }
+    let oldest_bit = set_bits.front().copied();
+    for bit in 0..bits {
+        let next = current ^ (1 << bit);
+        let is_setting = next & (1 << bit) != 0;
+        if visited[next as usize] || (!is_setting && oldest_bit != Some(bit)) {
+            continue;
+        }
+        visited[next as usize] = true;
+        if is_setting {
+            set_bits.push_back(bit);
+        } else {
+            set_bits.pop_front();
+        }
+        path.push(next);
+        if extend_beckett(path, visited, set_bits, bits) {
+            return true;
+        }
+        path.pop();
+        if is_setting {
+            set_bits.pop_back();
+        } else {
+            set_bits.push_front(bit);
+        }
+        visited[next as usize] = false;
+    }
+    false
+}
+
+// None for the widths without any Beckett-Gray code, such as 3 and 4
+pub fn beckett_gray_code(bits: u32) -> Option<Vec<u64>> {
+    assert_search_bits(bits);
+    let length = 1usize << bits;
+    let mut visited = vec![false; length];
+    visited[0] = true;
+    let mut path = vec![0];
+    extend_beckett(&mut path, &mut visited, &mut VecDeque::new(), bits).then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gray_code::GraySequence;
+
+    #[test]
+    fn n_ary_gray_code_test_ok() {
+        let codes = n_ary_gray_code(3, 2);
+        let expected: Vec<Vec<u32>> = [
+            [0, 0],
+            [1, 0],
+            [2, 0],
+            [2, 1],
+            [1, 1],
+            [0, 1],
+            [0, 2],
+            [1, 2],
+            [2, 2],
+        ]
+        .iter()
+        .map(|code| code.to_vec())
+        .collect();
+        assert_eq!(codes, expected);
+        assert!(is_n_ary_gray_code(&codes, 3, 2, false));
+        assert!(!is_n_ary_gray_code(&codes, 3, 2, true));
+        assert!(is_n_ary_gray_code(&n_ary_gray_code(4, 3), 4, 3, true));
+        assert!(is_n_ary_gray_code(&n_ary_gray_code(2, 5), 2, 5, true));
+        assert!(is_n_ary_gray_code(&n_ary_gray_code(5, 3), 5, 3, false));
+    }
+
+    #[test]
+    fn n_ary_gray_code_validator_err() {
+        let mut codes = n_ary_gray_code(3, 2);
+        codes.swap(2, 3);
+        assert!(!is_n_ary_gray_code(&codes, 3, 2, false));
+        let codes = n_ary_gray_code(3, 2);
+        assert!(!is_n_ary_gray_code(&codes[1..], 3, 2, false));
+    }
+
+    #[test]
+    fn balanced_gray_code_test_ok() {
+        for bits in 1..=MAX_SEARCH_BITS {
+            let codes = balanced_gray_code(bits).unwrap();
+            assert!(is_balanced_gray_code(&codes, bits), "{bits} bits");
+        }
+        assert_eq!(
+            transition_counts(&balanced_gray_code(4).unwrap(), 4),
+            vec![4, 4, 4, 4]
+        );
+        let reflected: Vec<u64> = GraySequence::new(4).collect();
+        assert!(is_binary_gray_code(&reflected, 4, true));
+        assert!(!is_balanced_gray_code(&reflected, 4));
+    }
+
+    #[test]
+    fn gray_code_validators_reject_out_of_range() {
+        assert!(!is_balanced_gray_code(&[0, 1 << 10], 2));
+        assert_eq!(transition_counts(&[0, 1 << 10], 2), vec![0, 0]);
+        assert!(!is_binary_gray_code(&[0, 1], 64, true));
+        assert!(!is_binary_gray_code(&[0, 1], 200, true));
+        assert!(!is_n_ary_gray_code(&[vec![0; 40]], 3, 40, false));
+    }
+
+    #[test]
+    fn monotone_gray_code_test_ok() {
+        for bits in 1..=MAX_SEARCH_BITS {
+            let codes = monotone_gray_code(bits).unwrap();
+            assert!(is_monotone_gray_code(&codes, bits), "{bits} bits");
+        }
+        let reflected: Vec<u64> = GraySequence::new(3).collect();
+        assert!(!is_monotone_gray_code(&reflected, 3));
+    }
+
+    #[test]
+    fn beckett_gray_code_test_ok() {
+        for bits in [1, 2, 5] {
+            let codes = beckett_gray_code(bits).unwrap();
+            assert!(is_beckett_gray_code(&codes, bits), "{bits} bits");
+        }
+        assert_eq!(beckett_gray_code(3), None);
+        assert_eq!(beckett_gray_code(4), None);
+        let reflected: Vec<u64> = GraySequence::new(2).collect();
+        assert!(is_beckett_gray_code(&reflected, 2));
+        let reflected: Vec<u64> = GraySequence::new(3).collect();
+        assert!(!is_beckett_gray_code(&reflected, 3));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod encoder;
+pub mod gray_code;
+pub mod gray_families;
@@ -1,3 +1,9 @@
+use ex02::{
+    encoder::{encoder_readings, EncoderDecoder},
+    gray_code::{gray_code, gray_decode, gray_transition_bit, GraySequence},
+    gray_families::{balanced_gray_code, beckett_gray_code, n_ary_gray_code, transition_counts},
+};
+
 fn main() {
     println!("gray_code of 0 : {}", gray_code(0));
     println!("gray_code of 1 : {}", gray_code(1));
@@ -8,25 +14,25 @@ fn main() {
     println!("gray_code of 6 : {}", gray_code(6));
     println!("gray_code of 7 : {}", gray_code(7));
     println!("gray_code of 8 : {}", gray_code(8));
-}
-
-fn gray_code(n: u32) -> u32 {
-    n ^ (n >> 1)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn gray_code_test_ok() {
-        assert_eq!(gray_code(0), 0);
-        assert_eq!(gray_code(1), 1);
-        assert_eq!(gray_code(2), 3);
-        assert_eq!(gray_code(3), 2);
-        assert_eq!(gray_code(4), 6);
-        assert_eq!(gray_code(5), 7);
-        assert_eq!(gray_code(6), 5);
-        assert_eq!(gray_code(7), 4);
-        assert_eq!(gray_code(8), 12);
+    println!("gray_decode of 12 : {}", gray_decode(12u32));
+    for (index, code) in GraySequence::new(3).enumerate() {
+        println!(
+            "{code:03b} then flip bit {}",
+            gray_transition_bit(3, index as u64)
+        );
+    }
+    println!("base 3 reflected Gray code : {:?}", n_ary_gray_code(3, 2));
+    if let Some(codes) = balanced_gray_code(5) {
+        println!(
+            "balanced 5 bit transition counts : {:?}",
+            transition_counts(&codes, 5)
+        );
     }
+    println!("beckett 5 bit : {:?}", beckett_gray_code(5));
+    let readings = encoder_readings(2, &[0, 1, 2, 3, 2, 3, 0]);
+    let report = EncoderDecoder::quadrature().decode_stream(&readings);
+    println!(
+        "quadrature position : {}, bounces : {}",
+        report.position, report.bounces
+    );
 }
@@ -1,8 +1,4 @@
-use concrete_tree::ConcreteFormulaTree;
-
-mod concrete_tree;
-mod error;
-mod operation;
+use boolean_logic::concrete_tree::ConcreteFormulaTree;
 
 fn main() {
     println!("Resolving 10& : {}", eval_formula("10&"));
@@ -12,6 +8,10 @@ fn main() {
     println!("Resolving 1011||= : {}", eval_formula("1011||="));
     println!("Resolving 1! : {}", eval_formula("1!"));
     println!("Resolving 01> : {}", eval_formula("01>"));
+    println!(
+        "Resolving (1 & 0) | !0 -> 1 : {}",
+        eval_infix_formula("(1 & 0) | !0 -> 1")
+    );
 }
 
 pub fn eval_formula(input: &str) -> bool {
@@ -25,7 +25,19 @@ pub fn eval_formula(input: &str) -> bool {
     formula.resolve_tree()
 }
 
+pub fn eval_infix_formula(input: &str) -> bool {
+    let formula = match ConcreteFormulaTree::build_from_infix(input) {
+        Ok(formula) => formula,
+        Err(e) => {
+            println!("{e}");
+            return false;
+        }
+    };
+    formula.resolve_tree()
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     #[test]
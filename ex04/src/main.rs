@@ -1,10 +1,14 @@
-use truth_table::TruthTable;
+use boolean_logic::truth_table::TruthTable;
 
-mod error;
-mod operation;
-mod tree_node;
-mod truth_table;
 fn main() {
     let tree = TruthTable::build_from_str("AB|C&").unwrap();
-    tree.compute_truth_table();
+    tree.compute_truth_table().unwrap();
+    tree.compute_truth_table_in_gray_order().unwrap();
+    let tree = TruthTable::build_from_infix("(A | B) & C -> D").unwrap();
+    tree.compute_truth_table().unwrap();
+    let tree = TruthTable::build_from_separated_str("clk_en x17 reset ! | &").unwrap();
+    tree.compute_truth_table().unwrap();
+    if let Err(e) = TruthTable::build_from_infix("(A & ) | B) C") {
+        println!("{e}");
+    }
 }
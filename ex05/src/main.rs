@@ -1,12 +1,21 @@
-use truth_table::TruthTable;
-
-mod error;
-mod operation;
-mod tree_node;
-mod truth_table;
+use boolean_logic::{operation::Notation, truth_table::TruthTable};
 
 fn main() {
     let mut tree = TruthTable::build_from_str("ABC>=").unwrap();
     tree.turn_into_negation_normal_form();
     tree.print_rpn_from_tree();
+    let mut tree = TruthTable::build_from_infix("A = (B -> C)").unwrap();
+    tree.turn_into_negation_normal_form();
+    tree.print_rpn_from_tree();
+    let mut tree = TruthTable::build_from_infix("not (clk_en and x17)").unwrap();
+    tree.turn_into_negation_normal_form();
+    tree.print_rpn_from_tree();
+    let mut tree = TruthTable::build_from_infix("A ↔ (B → C)").unwrap();
+    tree.turn_into_negation_normal_form();
+    tree.print_rpn_in_notation(Notation::Unicode);
+    tree.print_rpn_in_notation(Notation::Latex);
+    println!("{tree}");
+    if let Err(e) = TruthTable::build_from_infix("(A & ) | B) C") {
+        println!("{e}");
+    }
 }
@@ -1,12 +1,17 @@
-use truth_table::TruthTable;
-
-mod error;
-mod operation;
-mod tree_node;
-mod truth_table;
+use boolean_logic::{operation::Notation, truth_table::TruthTable};
 
 fn main() {
     let mut tree = TruthTable::build_from_str("AB&C&D&").unwrap();
     tree.turn_into_conjuctive_normal_form();
     tree.print_rpn_from_tree();
+    let mut tree = TruthTable::build_from_infix("A & B & C & D").unwrap();
+    tree.turn_into_conjuctive_normal_form();
+    tree.print_rpn_from_tree();
+    println!("{}", tree.to_infix_wrapped(Notation::Unicode, 12));
+    let tree = TruthTable::build_from_prefix("| & A B ! C").unwrap();
+    println!("{}", tree.to_rpn(Notation::Symbol));
+    println!("{}", tree.to_prefix(Notation::Word));
+    if let Err(e) = TruthTable::build_from_infix("(A & ) | B) C") {
+        println!("{e}");
+    }
 }